@@ -0,0 +1,45 @@
+use std::{collections::HashSet, str::FromStr};
+
+pub use aoc_common::Result;
+use aoc_common::{Answer, Input, Solution};
+
+pub fn calc_final_frequency(input: &str) -> Result<i32> {
+    let frequency_update: i32 = input.split('\n').flat_map(i32::from_str).sum();
+    Ok(frequency_update)
+}
+
+pub fn find_duplicated_frequency(input: &str) -> Result<i32> {
+    let frequency_update: Vec<i32> = input.split('\n').flat_map(i32::from_str).collect();
+
+    let mut frequency_list = HashSet::new();
+    let mut last_frequency = 0;
+
+    for change in frequency_update.iter().cycle() {
+        last_frequency += change;
+
+        if !frequency_list.insert(last_frequency) {
+            break;
+        }
+    }
+
+    Ok(last_frequency)
+}
+
+pub struct Puzzle {
+    input: String,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self> {
+        let input = input.as_str().to_owned();
+        Ok(Self { input })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        calc_final_frequency(&self.input).map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        find_duplicated_frequency(&self.input).map(Answer::from)
+    }
+}
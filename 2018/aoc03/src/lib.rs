@@ -0,0 +1,108 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    str::FromStr,
+};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub use aoc_common::Result;
+use aoc_common::{Answer, Input, Solution};
+pub type FabricPlan = HashMap<(u32, u32), Vec<u32>>;
+
+pub fn parse_claims(input: &str) -> Vec<Claim> {
+    input.split('\n').flat_map(Claim::from_str).collect()
+}
+
+pub fn generate_plan(claims: &[Claim]) -> Result<FabricPlan> {
+    let mut plan = FabricPlan::new();
+
+    for claim in claims {
+        let (x, y) = claim.origin;
+        let (width, height) = claim.size;
+
+        for x_index in x..(x + width) {
+            for y_index in y..(y + height) {
+                let ids = plan.entry((x_index, y_index)).or_default();
+                ids.push(claim.id);
+            }
+        }
+    }
+
+    Ok(plan)
+}
+
+pub fn calc_overlapping_squares(plan: &FabricPlan) -> Result<usize> {
+    let overlapping = plan.values().filter(|ids| ids.len() > 1).count();
+    Ok(overlapping)
+}
+
+pub fn find_unique_claim(plan: &FabricPlan, claims: &[Claim]) -> Result<u32> {
+    let overlapping_ids: HashSet<&u32> = plan
+        .values()
+        .filter(|ids| ids.len() > 1)
+        .flatten()
+        .collect();
+
+    let unique_claim = claims
+        .iter()
+        .rfind(|claim| !overlapping_ids.contains(&claim.id))
+        .ok_or_else(|| "Couldn't determine any unique claim.".to_string())?;
+
+    Ok(unique_claim.id)
+}
+
+// -----------------------------------------------------------------------------
+// Claim
+// -----------------------------------------------------------------------------
+
+pub struct Claim {
+    id: u32,
+    origin: (u32, u32),
+    size: (u32, u32),
+}
+
+impl FromStr for Claim {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self> {
+        lazy_static! {
+            static ref CLAIM_RE: Regex = Regex::new(
+                r"^#(?P<id>\d+) @ (?P<origin_x>\d+),(?P<origin_y>\d+): (?P<width>\d+)x(?P<height>\d+)",
+            ).expect("Predefined claim regex failed to compile.");
+        }
+
+        let capture = CLAIM_RE
+            .captures(input)
+            .ok_or_else(|| format!("Couldn't parse claim line: {}", input))?;
+
+        let id = capture["id"].parse()?;
+        let origin = (capture["origin_x"].parse()?, capture["origin_y"].parse()?);
+        let size = (capture["width"].parse()?, capture["height"].parse()?);
+
+        Ok(Claim { id, origin, size })
+    }
+}
+
+pub struct Puzzle {
+    claims: Vec<Claim>,
+    plan: FabricPlan,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self> {
+        let claims = parse_claims(input.as_str());
+        let plan = generate_plan(&claims)?;
+
+        Ok(Self { claims, plan })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        calc_overlapping_squares(&self.plan).map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        find_unique_claim(&self.plan, &self.claims).map(Answer::from)
+    }
+}
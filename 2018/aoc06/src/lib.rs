@@ -0,0 +1,98 @@
+use std::{collections::HashMap, str::FromStr};
+
+pub mod coordinate;
+use crate::coordinate::{Coordinate, Distance, Grid};
+
+pub use aoc_common::Result;
+use aoc_common::{Answer, Input, Solution};
+pub type Identifier = usize;
+
+pub fn parse_coordinates(input: &str) -> Result<Vec<Coordinate>> {
+    input.lines().map(Coordinate::from_str).collect()
+}
+
+pub fn calc_distances(spots: &[Coordinate]) -> Grid {
+    let width = spots
+        .iter()
+        .map(|coordinate| coordinate.x)
+        .max()
+        .expect("We always operate on at least one coordinate");
+
+    let height = spots
+        .iter()
+        .map(|coordinate| coordinate.y)
+        .max()
+        .expect("We always operate on at least one coordinate");
+
+    let mut distance_overview = HashMap::new();
+    for x in 0..=width {
+        for y in 0..=height {
+            let border_tile = x == 0 || y == 0 || x == width || y == height;
+            let tile = Coordinate { x, y, border_tile };
+
+            let owner: HashMap<Identifier, Distance> = spots
+                .iter()
+                .map(|coordinate| coordinate.distance(&tile))
+                .enumerate()
+                .collect();
+
+            distance_overview.insert(tile, owner);
+        }
+    }
+
+    distance_overview
+}
+
+pub fn find_max_finite_area(grid: &Grid) -> Result<(Identifier, usize)> {
+    let mut ownership = HashMap::new();
+
+    grid.iter()
+        .map(|(coordinate, distances)| {
+            let potential_owner = coordinate::find_owner(distances);
+            (coordinate, potential_owner)
+        })
+        .for_each(|(coordinate, potential_owner)| match potential_owner {
+            None => (),
+            Some(identifier) => {
+                let coordinates = ownership.entry(identifier).or_insert_with(Vec::new);
+                coordinates.push(coordinate);
+            }
+        });
+
+    let (identifier, coordinates) = ownership
+        .into_iter()
+        .filter(|(_, coordinates)| coordinates.iter().all(|coordinate| !coordinate.border_tile))
+        .max_by_key(|(_, coordinates)| coordinates.len())
+        .ok_or_else(|| "Couldn't determine the size of any finite area.".to_string())?;
+
+    Ok((identifier, coordinates.len()))
+}
+
+pub fn find_area_with_distances_under(grid: &Grid, upper_limit: u32) -> usize {
+    grid.values()
+        .map(coordinate::total_distance)
+        .filter(|&distance| distance < upper_limit)
+        .count()
+}
+
+pub struct Puzzle {
+    distances: Grid,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self> {
+        let coordinates = parse_coordinates(input.as_str())?;
+        let distances = calc_distances(&coordinates);
+
+        Ok(Self { distances })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let (_, area_size) = find_max_finite_area(&self.distances)?;
+        Ok(area_size.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(find_area_with_distances_under(&self.distances, 10000).into())
+    }
+}
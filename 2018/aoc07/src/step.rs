@@ -12,9 +12,7 @@ pub struct StepCell(pub Rc<RefCell<Step>>);
 
 impl StepCell {
     pub fn new(step: Step) -> Self {
-        Self {
-            0: Rc::new(RefCell::new(step)),
-        }
+        Self(Rc::new(RefCell::new(step)))
     }
 }
 
@@ -65,7 +63,7 @@ impl Eq for Step {}
 
 impl PartialOrd for Step {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.identifier.partial_cmp(&other.identifier)
+        Some(self.cmp(other))
     }
 }
 
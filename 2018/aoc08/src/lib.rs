@@ -0,0 +1,102 @@
+use std::{error::Error, str::FromStr};
+
+pub use aoc_common::Result;
+use aoc_common::{Answer, Input, Solution};
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Clone, Hash)]
+pub struct Node {
+    children: Vec<Node>,
+    meta_data: Vec<u32>,
+}
+
+impl Node {
+    fn from_iterator(mut input: &mut dyn Iterator<Item = &str>) -> Result<Self> {
+        let num_children: usize = input
+            .next()
+            .ok_or("Couldn't parse number of children from header.")?
+            .parse()?;
+
+        let num_meta_data: usize = input
+            .next()
+            .ok_or("Couldn't parse number of meta data entries from header.")?
+            .parse()?;
+
+        let mut children = vec![];
+        for _ in 0..num_children {
+            let child: Node = Node::from_iterator(&mut input)?;
+            children.push(child);
+        }
+
+        let mut meta_data = vec![];
+        for _ in 0..num_meta_data {
+            let data: u32 = input
+                .next()
+                .ok_or("Couldn't parse an expected meta value.")?
+                .parse()?;
+
+            meta_data.push(data);
+        }
+
+        Ok(Node {
+            children,
+            meta_data,
+        })
+    }
+
+    pub fn sum_meta_data(&self) -> u32 {
+        let mut value = 0u32;
+        let mut outstanding_nodes = vec![self];
+
+        while let Some(node) = outstanding_nodes.pop() {
+            value += node.meta_data.iter().sum::<u32>();
+
+            let mut child_references = node.children.iter().collect();
+            outstanding_nodes.append(&mut child_references);
+        }
+
+        value
+    }
+
+    pub fn calc_value(&self) -> u32 {
+        if self.children.is_empty() {
+            return self.meta_data.iter().sum();
+        }
+
+        self.meta_data
+            .iter()
+            .filter(|&&value| value > 0)
+            .map(|&value| {
+                self.children
+                    .get((value - 1) as usize)
+                    .map_or(0, Node::calc_value)
+            })
+            .sum()
+    }
+}
+
+impl FromStr for Node {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::from_iterator(&mut input.split_whitespace())
+    }
+}
+
+pub struct Puzzle {
+    root: Node,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self> {
+        let root = input.parse()?;
+        Ok(Self { root })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.root.sum_meta_data().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.root.calc_value().into())
+    }
+}
@@ -0,0 +1,82 @@
+use regex::Regex;
+
+mod list;
+use crate::list::{Circle, Node};
+
+pub use aoc_common::Result;
+use aoc_common::{Answer, Input, Solution};
+
+#[derive(Debug)]
+struct Marble {
+    value: u32,
+}
+
+impl Marble {
+    fn new(value: u32) -> Self {
+        Marble { value }
+    }
+}
+
+/// Parses the number of players and the value of the last marble from the game setup.
+pub fn parse_game_setup(input: &str) -> Result<(u32, u32)> {
+    let game_rules_re = Regex::new(r"^(?P<player>\d+) players; .+ (?P<marbles>\d+) points")?;
+    let captures = game_rules_re
+        .captures(input)
+        .ok_or_else(|| format!("Couldn't parse game setup from {}", input))?;
+
+    let num_players = captures["player"].parse::<u32>()?;
+    let num_marbles = captures["marbles"].parse::<u32>()?;
+
+    Ok((num_players, num_marbles))
+}
+
+pub fn play_marbles(num_players: u32, num_marbles: u32) -> Result<u64> {
+    let mut scores = vec![0; num_players as usize];
+
+    let root = Node::new(Marble::new(0));
+    let mut circle = Circle::new(root);
+
+    for value in 1..=num_marbles {
+        if value % 23 != 0 {
+            circle.move_forward(1);
+            circle.insert(Marble::new(value));
+        } else {
+            circle.move_backwards(7);
+            let removed_marble = circle.remove()?;
+
+            let player = value % num_players;
+            scores[player as usize] += value + removed_marble.value;
+        }
+    }
+
+    let max_score = scores
+        .iter()
+        .max()
+        .ok_or("We need at least one elf to play a game.")?;
+
+    Ok(u64::from(*max_score))
+}
+
+pub struct Puzzle {
+    num_players: u32,
+    num_marbles: u32,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self> {
+        let (num_players, num_marbles) = parse_game_setup(input.as_str())?;
+
+        Ok(Self {
+            num_players,
+            num_marbles,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        play_marbles(self.num_players, self.num_marbles).map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        play_marbles(self.num_players, self.num_marbles * 100).map(Answer::from)
+    }
+}
@@ -32,7 +32,7 @@ where
         let mut peek = Rc::clone(&self.current);
         for _ in 0..steps {
             let next = match &peek.borrow().next {
-                Some(link) => Rc::clone(&link),
+                Some(link) => Rc::clone(link),
                 _ => unreachable!("This can only trigger if our remove logic is flawed"),
             };
 
@@ -50,7 +50,7 @@ where
         let mut peek = Rc::clone(&self.current);
         for _ in 0..steps {
             let prev = match &peek.borrow().prev {
-                Some(link) => Rc::clone(&link),
+                Some(link) => Rc::clone(link),
                 _ => unreachable!("This can only trigger if our remove logic is flawed"),
             };
 
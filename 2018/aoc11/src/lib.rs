@@ -0,0 +1,112 @@
+pub use aoc_common::Result;
+use aoc_common::{Answer, Input, Solution};
+
+#[derive(Debug)]
+pub struct FuelCell {
+    pub rack_id: u32,
+    pub power_level: i64,
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    cells: Vec<Vec<FuelCell>>,
+    size: (usize, usize),
+}
+
+impl Grid {
+    pub fn new(serial_number: u32, width: usize, height: usize) -> Self {
+        let mut cells = vec![];
+
+        for y in 0..height {
+            let mut row = vec![];
+
+            for x in 0..width {
+                let rack_id: u32 = (x as u32) + 10;
+
+                let mut power_level = i64::from(rack_id * (y as u32));
+                power_level += i64::from(serial_number);
+                power_level *= i64::from(rack_id);
+                power_level /= 100;
+                power_level %= 10;
+                power_level -= 5;
+
+                let cell = FuelCell {
+                    rack_id,
+                    power_level,
+                };
+                row.push(cell);
+            }
+
+            cells.push(row);
+        }
+
+        let size = (width, height);
+        Grid { cells, size }
+    }
+
+    pub fn find_max_power(
+        &self,
+        window_width: usize,
+        window_height: usize,
+    ) -> Result<((usize, usize), i64)> {
+        let max_x = self.size.0 - window_width;
+        let max_y = self.size.1 - window_height;
+
+        (0..max_y)
+            .flat_map(|y| (0..max_x).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let combined_power = self
+                    .cells
+                    .iter()
+                    .skip(y)
+                    .take(window_height)
+                    .map(|cells| {
+                        cells
+                            .iter()
+                            .skip(x)
+                            .take(window_width)
+                            .map(|cell| cell.power_level)
+                            .sum::<i64>()
+                    })
+                    .sum::<i64>();
+
+                ((x, y), combined_power)
+            })
+            .max_by_key(|(_, power)| *power)
+            .ok_or_else(|| Box::from("An empty grid has now max power window of any size."))
+    }
+
+    pub fn find_max_power_window(&self) -> Result<((usize, usize), usize, i64)> {
+        let max_square_side = usize::min(self.size.0, self.size.1);
+
+        (0..max_square_side)
+            .flat_map(|size| {
+                self.find_max_power(size, size)
+                    .map(|(coordinate, power)| (coordinate, size, power))
+                    .ok()
+            })
+            .max_by_key(|(_, _, power)| *power)
+            .ok_or_else(|| Box::from("An empty grid has now max power window of any size."))
+    }
+}
+
+pub struct Puzzle {
+    grid: Grid,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self> {
+        let grid = Grid::new(input.parse()?, 300, 300);
+        Ok(Self { grid })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let ((x, y), _) = self.grid.find_max_power(3, 3)?;
+        Ok(format!("{},{}", x, y).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let ((x, y), size, _) = self.grid.find_max_power_window()?;
+        Ok(format!("{},{},{}", x, y, size).into())
+    }
+}
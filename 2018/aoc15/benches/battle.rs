@@ -0,0 +1,33 @@
+//! Measures the day 15 simulation on the real puzzle input.
+//!
+//! Run `cargo bench -p aoc15 -- --save-baseline <name>` before and
+//! `cargo bench -p aoc15 -- --baseline <name>` after a change to compare both.
+
+use std::str::FromStr;
+
+use aoc15::{game::Game, search};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const INPUT: &str = include_str!("../input/input.txt");
+
+fn battle(criterion: &mut Criterion) {
+    let game = Game::from_str(INPUT).expect("puzzle input must be valid");
+
+    let mut group = criterion.benchmark_group("day15");
+    group.sample_size(10);
+
+    group.bench_function("battle", |bencher| {
+        bencher.iter(|| black_box(&game).clone().run())
+    });
+    group.bench_function("battle_without_elf_losses", |bencher| {
+        bencher.iter(|| search::simulate(black_box(&game), 20))
+    });
+    group.bench_function("binary_search", |bencher| {
+        bencher.iter(|| search::binary_search(black_box(&game)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, battle);
+criterion_main!(benches);
@@ -0,0 +1,129 @@
+use std::{
+    env, fs,
+    io::{self, BufWriter, Write},
+    process,
+    str::FromStr,
+    time::Duration,
+};
+
+use aoc15::{
+    events::JsonLines,
+    game::Game,
+    render::{Recorder, TerminalRenderer},
+    scenario::Scenario,
+    search,
+    stepper::Stepper,
+    tournament::{Contender, Tournament},
+    Result,
+};
+use aoc_common::Input;
+
+const USAGE: &str = "Usage:
+    battle watch <input> [frame delay in ms]
+    battle record <input> <recording>
+    battle report <input> [elf attack power]
+    battle events <input> [elf attack power]
+    battle search <input>
+    battle scenario <scenario.toml>
+    battle tournament <input or scenario.toml>...
+    battle replay <recording> [frame delay in ms]
+    battle step <input or scenario.toml>";
+
+/// Battles of a tournament lasting longer end in a draw.
+const MAX_TOURNAMENT_ROUNDS: u32 = 500;
+
+fn main() -> Result<()> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let terminal = |frame_delay: Option<&&str>| -> Result<TerminalRenderer> {
+        Ok(match frame_delay {
+            Some(millis) => TerminalRenderer::new(Duration::from_millis(millis.parse()?)),
+            None => TerminalRenderer::default(),
+        })
+    };
+
+    match args.as_slice() {
+        ["watch", input, frame_delay @ ..] => {
+            let mut game = Game::from_str(Input::from_path(input)?.as_str())?;
+            game.run_with(&mut terminal(frame_delay.first())?)?;
+        }
+        ["record", input, recording] => {
+            let mut game = Game::from_str(Input::from_path(input)?.as_str())?;
+            let report = game.run_with(&mut Recorder::create(recording)?)?;
+
+            println!(
+                "Recorded {} rounds, {} wins with an outcome of {}.",
+                report.full_rounds, report.winner, report.outcome
+            );
+        }
+        ["report", input, elf_attack_power @ ..] => {
+            let mut game = Game::from_str(Input::from_path(input)?.as_str())?;
+            if let Some(elf_attack_power) = elf_attack_power.first() {
+                game.set_elf_attack_power(elf_attack_power.parse()?);
+            }
+
+            println!("{}", serde_json::to_string_pretty(&game.run()?)?);
+        }
+        ["events", input, elf_attack_power @ ..] => {
+            let mut game = Game::from_str(Input::from_path(input)?.as_str())?;
+            if let Some(elf_attack_power) = elf_attack_power.first() {
+                game.set_elf_attack_power(elf_attack_power.parse()?);
+            }
+
+            let stdout = io::stdout();
+            let mut events = JsonLines::new(BufWriter::new(stdout.lock()));
+            game.run_with_events(&mut events)?;
+            events.into_inner().flush()?;
+        }
+        ["search", input] => {
+            let game = Game::from_str(Input::from_path(input)?.as_str())?;
+            let minimal_attack_power = search::parallel_sweep(&game)?;
+
+            println!("{}", serde_json::to_string_pretty(&minimal_attack_power)?);
+        }
+        ["scenario", scenario] => {
+            let scenario = Scenario::from_str(&fs::read_to_string(scenario)?)?;
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&scenario.game()?.run()?)?
+            );
+        }
+        ["tournament", maps @ ..] if !maps.is_empty() => {
+            let maps = maps
+                .iter()
+                .map(|path| Ok((path.to_string(), load_game(path)?)))
+                .collect::<Result<Vec<_>>>()?;
+
+            let tournament = Tournament {
+                maps,
+                contenders: Contender::builtin(),
+                max_rounds: MAX_TOURNAMENT_ROUNDS,
+            };
+
+            println!("{}", serde_json::to_string_pretty(&tournament.run())?);
+        }
+        ["replay", recording, frame_delay @ ..] => {
+            terminal(frame_delay.first())?.replay(&fs::read_to_string(recording)?)?;
+        }
+        ["step", input] => {
+            let stdin = io::stdin();
+            Stepper::new(load_game(input)?).run(stdin.lock(), io::stdout())?;
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads a TOML scenario or the plain map of a puzzle input.
+fn load_game(path: &str) -> Result<Game> {
+    if path.ends_with(".toml") {
+        Ok(Scenario::from_str(&fs::read_to_string(path)?)?.game()?)
+    } else {
+        Ok(Game::from_str(Input::from_path(path)?.as_str())?)
+    }
+}
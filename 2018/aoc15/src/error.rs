@@ -0,0 +1,138 @@
+use std::{error::Error, fmt, io};
+
+use crate::location::Location;
+
+/// Reasons why a map or scenario can't be turned into a game.
+#[derive(Debug)]
+pub enum ScenarioError {
+    /// Rows of the map differ in length, lines start at 1.
+    RaggedMap {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    UnknownSymbol {
+        symbol: char,
+        location: Location,
+    },
+    /// The legend places units on the symbol of a wall or floor tile.
+    ReservedSymbol(char),
+    /// There aren't two races on the map which fight each other.
+    TooFewFactions,
+    /// An override refers to a location without a unit.
+    NoUnitAt(Location),
+    Toml(toml::de::Error),
+}
+
+/// Reasons why a battle ends without a winner.
+#[derive(Debug)]
+pub enum BattleError {
+    /// A full round passed without a single move or attack, so the battle would never end.
+    Stalemate {
+        full_rounds: u32,
+    },
+    Io(io::Error),
+}
+
+/// Reasons why the search for the minimal elf attack power fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchError {
+    NoElves,
+    /// Not even the maximal attack power lets the elves win without a single loss.
+    NoSolution {
+        max_elf_attack_power: u32,
+    },
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioError::RaggedMap {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {} of the map has {} instead of {} columns.",
+                line, found, expected
+            ),
+            ScenarioError::UnknownSymbol { symbol, location } => write!(
+                f,
+                "Unknown symbol {:?} at ({}, {}).",
+                symbol, location.x, location.y
+            ),
+            ScenarioError::ReservedSymbol(symbol) => write!(
+                f,
+                "Units can't use the symbol {:?}, it belongs to a map tile.",
+                symbol
+            ),
+            ScenarioError::TooFewFactions => {
+                write!(f, "The map needs at least two races fighting each other.")
+            }
+            ScenarioError::NoUnitAt(location) => {
+                write!(f, "No unit stands on ({}, {}).", location.x, location.y)
+            }
+            ScenarioError::Toml(source) => write!(f, "Couldn't read scenario: {}", source),
+        }
+    }
+}
+
+impl Error for ScenarioError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScenarioError::Toml(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<toml::de::Error> for ScenarioError {
+    fn from(source: toml::de::Error) -> Self {
+        ScenarioError::Toml(source)
+    }
+}
+
+impl fmt::Display for BattleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BattleError::Stalemate { full_rounds } => write!(
+                f,
+                "Stalemate after {} full rounds, no unit can move or attack anymore.",
+                full_rounds
+            ),
+            BattleError::Io(source) => write!(f, "Couldn't render the battle: {}", source),
+        }
+    }
+}
+
+impl Error for BattleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BattleError::Io(source) => Some(source),
+            BattleError::Stalemate { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for BattleError {
+    fn from(source: io::Error) -> Self {
+        BattleError::Io(source)
+    }
+}
+
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchError::NoElves => write!(f, "There are no elves to search an attack power for."),
+            SearchError::NoSolution {
+                max_elf_attack_power,
+            } => write!(
+                f,
+                "The elves can't win without losses, not even with an attack power of {}.",
+                max_elf_attack_power
+            ),
+        }
+    }
+}
+
+impl Error for SearchError {}
@@ -0,0 +1,168 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{location::Location, unit::UnitId};
+
+/// Everything which happens during a battle, in the order it happens.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event")]
+pub enum Event {
+    Moved {
+        unit: UnitId,
+        from: Location,
+        to: Location,
+    },
+    Attacked {
+        attacker: UnitId,
+        target: UnitId,
+        damage: u32,
+        hp_left: u32,
+    },
+    /// Round the unit died in, starting with round `1`.
+    Died { unit: UnitId, round: u32 },
+    /// Number of full rounds completed so far.
+    RoundEnded { round: u32 },
+}
+
+/// Gets notified about every event of a running game.
+pub trait Subscriber {
+    fn notify(&mut self, event: &Event) -> io::Result<()>;
+}
+
+/// Ignores every event.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NullSubscriber;
+
+impl Subscriber for NullSubscriber {
+    fn notify(&mut self, _: &Event) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Collects all events in memory.
+impl Subscriber for Vec<Event> {
+    fn notify(&mut self, event: &Event) -> io::Result<()> {
+        self.push(event.clone());
+        Ok(())
+    }
+}
+
+/// Writes every event as a single line of JSON.
+#[derive(Debug)]
+pub struct JsonLines<WriterT: Write> {
+    output: WriterT,
+}
+
+impl JsonLines<BufWriter<File>> {
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(JsonLines::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<WriterT: Write> JsonLines<WriterT> {
+    pub fn new(output: WriterT) -> Self {
+        JsonLines { output }
+    }
+
+    pub fn into_inner(self) -> WriterT {
+        self.output
+    }
+}
+
+impl<WriterT: Write> Subscriber for JsonLines<WriterT> {
+    fn notify(&mut self, event: &Event) -> io::Result<()> {
+        serde_json::to_writer(&mut self.output, event)?;
+        writeln!(self.output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::game::Game;
+
+    const COMBAT: &str = "#######\n\
+                          #.G...#\n\
+                          #...EG#\n\
+                          #.#.#G#\n\
+                          #..G#E#\n\
+                          #.....#\n\
+                          #######";
+
+    #[test]
+    fn log_every_event() -> crate::Result<()> {
+        let mut game = Game::from_str(COMBAT)?;
+        let mut events = vec![];
+
+        let report = game.run_with_events(&mut events)?;
+
+        let rounds = events
+            .iter()
+            .filter(|event| matches!(event, Event::RoundEnded { .. }))
+            .count();
+        assert_eq!(report.full_rounds as usize, rounds);
+
+        let deaths = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Died { unit, round } => Some((*unit, *round)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let casualties = report
+            .casualties
+            .iter()
+            .map(|casualty| (casualty.id, casualty.round))
+            .collect::<Vec<_>>();
+        assert_eq!(casualties, deaths);
+
+        let damage = events
+            .iter()
+            .map(|event| match event {
+                Event::Attacked { damage, .. } => *damage,
+                _ => 0,
+            })
+            .sum::<u32>();
+        assert_eq!(report.damage_dealt.values().sum::<u32>(), damage);
+
+        // The first goblin steps right towards the elf in the first round.
+        assert_eq!(
+            Event::Moved {
+                unit: UnitId(0),
+                from: Location::new(2, 1),
+                to: Location::new(3, 1),
+            },
+            events[0]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_json_lines() -> crate::Result<()> {
+        let mut game = Game::from_str(COMBAT)?;
+        let mut events = vec![];
+        let mut writer = JsonLines::new(vec![]);
+
+        game.clone().run_with_events(&mut events)?;
+        game.run_with_events(&mut writer)?;
+
+        let written = String::from_utf8(writer.into_inner())?;
+        let parsed = written
+            .lines()
+            .map(serde_json::from_str::<Event>)
+            .collect::<serde_json::Result<Vec<_>>>()?;
+
+        assert_eq!(events, parsed);
+        assert!(written.starts_with(r#"{"event":"Moved","unit":0,"from":{"y":1,"x":2}"#));
+
+        Ok(())
+    }
+}
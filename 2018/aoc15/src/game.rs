@@ -1,315 +1,650 @@
 use std::{
-    cell::RefCell,
-    collections::{BTreeMap, BinaryHeap, HashSet, VecDeque},
-    error::Error,
+    collections::{BTreeMap, VecDeque},
     fmt::{self, Display, Formatter},
-    rc::Rc,
+    io, mem,
     str::FromStr,
-    thread,
-    time::Duration,
+    sync::Arc,
 };
 
 use crate::{
+    error::{BattleError, ScenarioError},
+    events::{Event, NullSubscriber, Subscriber},
     location::Location,
     map::Map,
+    render::{NullRenderer, Renderer},
+    report::{BattleReport, Casualty, Survivor},
+    scenario::Legend,
+    strategy::{Destination, PuzzleRules, Strategy},
     tile::Tile,
-    unit::{Race, Unit},
-    Result,
+    unit::{Race, Unit, UnitId},
 };
 
-pub type UnitHandle = Rc<RefCell<Unit>>;
-
+/// Clones are independent snapshots of the game, which can be simulated on their own.
 #[derive(Debug, Clone)]
 pub struct Game {
     map: Map,
-    units: BTreeMap<Location, UnitHandle>,
+    legend: Legend,
+    /// Strategies of races which don't follow the rules of the puzzle.
+    strategies: BTreeMap<Race, Arc<dyn Strategy>>,
+    /// Every unit of the game, including the dead ones, indexed by their id.
+    units: Vec<Unit>,
+    /// Unit alive on each tile of the map.
+    occupancy: Vec<Option<UnitId>>,
+    /// Units which still take their turn in the current round, the next one last.
+    pending_turns: Vec<UnitId>,
+    /// No unit moved or attacked so far in the current round.
+    idle_round: bool,
+    full_rounds: u32,
+    casualties: Vec<Casualty>,
+    damage_dealt: BTreeMap<Race, u32>,
+    abort_on_elf_death: bool,
+    /// Distances to the moving unit, reused by every move.
+    from_unit: DistanceMap,
+    /// Distances to the square the moving unit heads for, reused by every move.
+    from_destination: DistanceMap,
+}
+
+/// Buffers of a breadth-first search, which are reused between searches.
+#[derive(Debug, Clone, Default)]
+struct DistanceMap {
+    distances: Vec<Option<u32>>,
+    queue: VecDeque<usize>,
+}
+
+impl DistanceMap {
+    /// Stores the distance of every tile reachable from the start tile.
+    fn fill<NeighborsT>(
+        &mut self,
+        start: usize,
+        num_tiles: usize,
+        neighbors: impl Fn(usize) -> NeighborsT,
+    ) where
+        NeighborsT: Iterator<Item = usize>,
+    {
+        self.distances.clear();
+        self.distances.resize(num_tiles, None);
+        self.queue.clear();
+
+        self.distances[start] = Some(0);
+        self.queue.push_back(start);
+
+        while let Some(index) = self.queue.pop_front() {
+            let distance = self.distances[index].map(|distance| distance + 1);
+
+            for adjacent in neighbors(index) {
+                if self.distances[adjacent].is_none() {
+                    self.distances[adjacent] = distance;
+                    self.queue.push_back(adjacent);
+                }
+            }
+        }
+    }
+
+    fn get(&self, index: usize) -> Option<u32> {
+        self.distances[index]
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum GameResult {
     NotYetDone,
     Finished(Race),
+    /// An elf died during a run without elf losses.
+    Aborted,
+    /// A full round passed without a single move or attack. Strategies only decide based on the
+    /// state of the game, so nothing will ever change again.
+    Stalemate,
 }
 
 impl Game {
     pub fn count_elves(&self) -> usize {
-        self.units
-            .values()
-            .filter(|unit| unit.borrow().race == Race::Elf)
-            .filter(|unit| unit.borrow().alive())
-            .count()
+        self.units().filter(|unit| unit.race.is_elf()).count()
     }
 
-    pub fn set_elf_attack_power(&self, ap: u32) {
+    pub fn set_elf_attack_power(&mut self, ap: u32) {
         self.units
-            .values()
-            .filter(|unit| unit.borrow().race == Race::Elf)
-            .for_each(|elf| elf.borrow_mut().attack_power = ap);
+            .iter_mut()
+            .filter(|unit| unit.race.is_elf())
+            .for_each(|elf| elf.attack_power = ap);
     }
 
-    pub fn run(&mut self) -> (u32, Race, u32) {
-        self.draw();
+    /// Parses a map whose units are described by the given legend instead of the puzzle's one.
+    ///
+    /// The map has to be rectangular and needs at least two races which fight each other.
+    pub fn with_legend(input: &str, legend: Legend) -> Result<Self, ScenarioError> {
+        if let Some(&symbol) = legend
+            .units
+            .keys()
+            .find(|&&symbol| Tile::from_char(symbol).is_some())
+        {
+            return Err(ScenarioError::ReservedSymbol(symbol));
+        }
+
+        let rows = input
+            .lines()
+            .map(|row| row.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let height = rows
+            .iter()
+            .rposition(|row| !row.is_empty())
+            .map_or(0, |y| y + 1);
+        let width = rows.first().map_or(0, Vec::len);
+
+        if let Some((y, row)) = rows
+            .iter()
+            .take(height)
+            .enumerate()
+            .find(|(_, row)| row.len() != width)
+        {
+            return Err(ScenarioError::RaggedMap {
+                line: y + 1,
+                expected: width,
+                found: row.len(),
+            });
+        }
 
-        let mut turn_counter = 0;
+        let mut tiles = Vec::with_capacity(width * height);
+        let mut units = vec![];
+        let mut occupancy = vec![None; width * height];
 
-        let winner = loop {
-            let result = self.next_turn();
-            self.draw();
+        for (y, row) in rows.iter().take(height).enumerate() {
+            for (x, &symbol) in row.iter().enumerate() {
+                if let Some(tile) = Tile::from_char(symbol) {
+                    tiles.push(tile);
+                    continue;
+                }
 
-            if let GameResult::Finished(race) = result {
-                break race;
+                let location = Location::new(x, y);
+                let template = legend
+                    .template(symbol)
+                    .ok_or(ScenarioError::UnknownSymbol {
+                        symbol,
+                        location: location.clone(),
+                    })?;
+
+                let id = UnitId(units.len());
+                units.push(Unit::new(id, symbol, template, location));
+                occupancy[y * width + x] = Some(id);
+                tiles.push(Tile::Floor);
             }
+        }
 
-            turn_counter += 1;
-        };
+        let has_enemies = units.iter().any(|unit: &Unit| {
+            units
+                .iter()
+                .any(|other| legend.is_hostile(&unit.race, &other.race))
+        });
+        if !has_enemies {
+            return Err(ScenarioError::TooFewFactions);
+        }
 
-        let hit_points_left = self
-            .units
-            .values()
-            .map(|unit| unit.borrow().hit_points)
-            .sum();
+        let map = Map::new(width, height, tiles);
+
+        Ok(Game {
+            map,
+            legend,
+            strategies: BTreeMap::new(),
+            units,
+            occupancy,
+            pending_turns: vec![],
+            idle_round: true,
+            full_rounds: 0,
+            casualties: vec![],
+            damage_dealt: BTreeMap::new(),
+            abort_on_elf_death: false,
+            from_unit: DistanceMap::default(),
+            from_destination: DistanceMap::default(),
+        })
+    }
+
+    /// Overrides the hit points and attack power of the unit standing on the given location.
+    pub fn adjust_unit(
+        &mut self,
+        location: &Location,
+        hit_points: Option<u32>,
+        attack_power: Option<u32>,
+    ) -> Result<(), ScenarioError> {
+        let id = self
+            .unit_at(location)
+            .map(|unit| unit.id)
+            .ok_or_else(|| ScenarioError::NoUnitAt(location.clone()))?;
+
+        let unit = &mut self.units[id.0];
+        unit.hit_points = hit_points.unwrap_or(unit.hit_points);
+        unit.attack_power = attack_power.unwrap_or(unit.attack_power);
 
-        (turn_counter, winner, hit_points_left)
+        Ok(())
     }
 
-    pub fn next_turn(&mut self) -> GameResult {
-        let units: Vec<UnitHandle> = self.units.values().map(UnitHandle::clone).collect();
-        for unit in units {
-            if !unit.borrow().alive() {
-                continue;
+    /// Lets every unit of the race follow the given strategy.
+    pub fn set_strategy(&mut self, race: Race, strategy: Arc<dyn Strategy>) {
+        self.strategies.insert(race, strategy);
+    }
+
+    pub fn strategy(&self, race: &Race) -> &dyn Strategy {
+        match self.strategies.get(race) {
+            Some(strategy) => strategy.as_ref(),
+            None => &PuzzleRules,
+        }
+    }
+
+    /// Width and height of the map.
+    pub fn size(&self) -> (usize, usize) {
+        self.map.size
+    }
+
+    pub fn legend(&self) -> &Legend {
+        &self.legend
+    }
+
+    /// Units still alive in reading-order.
+    pub fn units(&self) -> impl Iterator<Item = &Unit> {
+        self.occupancy
+            .iter()
+            .filter_map(move |&id| Some(self.unit(id?)))
+    }
+
+    /// Unit alive on the given location, if it lies inside the map at all.
+    pub fn unit_at(&self, location: &Location) -> Option<&Unit> {
+        let (width, height) = self.map.size;
+        if location.x >= width || location.y >= height {
+            return None;
+        }
+
+        self.occupancy[self.map.index(location)].map(|id| self.unit(id))
+    }
+
+    /// Looks up a unit, dead or alive, by its id.
+    pub fn unit(&self, id: UnitId) -> &Unit {
+        &self.units[id.0]
+    }
+
+    /// Runs the game headless until one race has won or aborts as soon as the first elf dies.
+    pub fn run_without_elf_losses(&mut self) -> Option<BattleReport> {
+        self.abort_on_elf_death = true;
+
+        let report = loop {
+            match self.next_turn() {
+                GameResult::NotYetDone => continue,
+                GameResult::Finished(winner) => break Some(self.report(winner)),
+                GameResult::Aborted | GameResult::Stalemate => break None,
             }
+        };
 
-            {
-                let current_race = unit.borrow().race;
-                if self.has_race_won(current_race) {
-                    return GameResult::Finished(current_race);
-                }
+        self.abort_on_elf_death = false;
+        report
+    }
+
+    /// Runs the game headless until one race has won, gives up once the given number of full
+    /// rounds is over or in a stalemate.
+    pub fn run_for(&mut self, max_rounds: u32) -> Option<BattleReport> {
+        while self.full_rounds < max_rounds {
+            match self.next_turn() {
+                GameResult::NotYetDone => continue,
+                GameResult::Finished(winner) => return Some(self.report(winner)),
+                GameResult::Stalemate => return None,
+                GameResult::Aborted => unreachable!("Only runs without elf losses abort."),
             }
+        }
 
-            let nearby_target = {
-                let unit = unit.borrow();
-                self.get_nearby_target(&unit)
-            };
+        None
+    }
+
+    /// Runs the game headless until one race has won.
+    pub fn run(&mut self) -> Result<BattleReport, BattleError> {
+        self.run_with(&mut NullRenderer)
+    }
 
-            if nearby_target.is_none() {
-                self.move_unit(&unit)
+    /// Runs the game until one race has won and passes every round to the renderer.
+    pub fn run_with(&mut self, renderer: &mut impl Renderer) -> Result<BattleReport, BattleError> {
+        renderer.render(self.full_rounds, self)?;
+
+        let winner = loop {
+            match self.next_turn() {
+                GameResult::NotYetDone => renderer.render(self.full_rounds, self)?,
+                GameResult::Finished(race) => break race,
+                GameResult::Stalemate => return Err(self.stalemate()),
+                GameResult::Aborted => unreachable!("Only runs without elf losses abort."),
             }
+        };
 
-            let nearby_target = {
-                let unit = unit.borrow();
-                self.get_nearby_target(&unit)
-            };
+        let report = self.report(winner);
+        renderer.finish(&report, self)?;
 
-            if let Some(target) = nearby_target {
-                let unit = unit.borrow();
-                let mut target = target.borrow_mut();
+        Ok(report)
+    }
 
-                let is_dead = unit.attack(&mut target);
-                if is_dead {
-                    self.units.remove(&target.location);
-                }
+    /// Runs the game headless until one race has won and passes every event to the subscriber.
+    pub fn run_with_events(
+        &mut self,
+        subscriber: &mut impl Subscriber,
+    ) -> Result<BattleReport, BattleError> {
+        let winner = loop {
+            match self.next_turn_with(subscriber)? {
+                GameResult::NotYetDone => continue,
+                GameResult::Finished(race) => break race,
+                GameResult::Stalemate => return Err(self.stalemate()),
+                GameResult::Aborted => unreachable!("Only runs without elf losses abort."),
             }
+        };
+
+        Ok(self.report(winner))
+    }
+
+    fn stalemate(&self) -> BattleError {
+        BattleError::Stalemate {
+            full_rounds: self.full_rounds,
         }
+    }
 
-        GameResult::NotYetDone
+    fn report(&self, winner: Race) -> BattleReport {
+        let survivors = self
+            .units()
+            .map(|unit| Survivor {
+                id: unit.id,
+                race: unit.race.clone(),
+                location: unit.location.clone(),
+                hit_points: unit.hit_points,
+            })
+            .collect::<Vec<_>>();
+
+        let hit_points_left = survivors.iter().map(|unit| unit.hit_points).sum();
+
+        BattleReport {
+            full_rounds: self.full_rounds,
+            winner,
+            outcome: self.full_rounds * hit_points_left,
+            hit_points_left,
+            survivors,
+            casualties: self.casualties.clone(),
+            damage_dealt: self.damage_dealt.clone(),
+        }
     }
 
-    fn get_nearby_target(&self, unit: &Unit) -> Option<UnitHandle> {
-        unit.location
-            .adjacent()
-            .into_iter()
-            .flat_map(|location| self.units.get(&location))
-            .filter(|other_unit| other_unit.borrow().race != unit.race)
-            .min_by_key(|target| target.borrow().hit_points)
-            .map(UnitHandle::clone)
+    pub fn next_turn(&mut self) -> GameResult {
+        self.next_turn_with(&mut NullSubscriber)
+            .expect("The null subscriber never fails.")
     }
 
-    fn move_unit(&mut self, unit_ref: &UnitHandle) {
-        let mut unit = unit_ref.borrow_mut();
-        let distance_map = self.generate_distance_map(&unit.location);
+    /// Lets the next unit of the current round take its turn.
+    pub fn next_unit_turn(&mut self) -> GameResult {
+        self.next_unit_turn_with(&mut NullSubscriber)
+            .expect("The null subscriber never fails.")
+    }
 
-        let mut potential_targets = BTreeMap::new();
-        for target in self.units.values() {
-            if UnitHandle::ptr_eq(&target, &unit_ref) {
-                continue;
+    /// Plays the rest of the current round and notifies the subscriber about everything which
+    /// happens.
+    pub fn next_turn_with(&mut self, subscriber: &mut impl Subscriber) -> io::Result<GameResult> {
+        loop {
+            let result = self.next_unit_turn_with(subscriber)?;
+            if !matches!(result, GameResult::NotYetDone) || self.pending_turns.is_empty() {
+                return Ok(result);
             }
+        }
+    }
+
+    /// Lets the next unit of the current round take its turn and notifies the subscriber about
+    /// everything which happens.
+    pub fn next_unit_turn_with(
+        &mut self,
+        subscriber: &mut impl Subscriber,
+    ) -> io::Result<GameResult> {
+        if self.pending_turns.is_empty() {
+            self.pending_turns = self.occupancy.iter().rev().flatten().copied().collect();
+            self.idle_round = true;
+        }
 
-            let target = target.borrow();
-            if unit.race == target.race {
-                continue;
+        if let Some(id) = self.next_unit() {
+            let result = self.take_turn(id, subscriber)?;
+            if let GameResult::Finished(_) = result {
+                // The unit didn't act, it's still its turn.
+                return Ok(result);
             }
 
-            let target_locations = self.free_adjacent(&target.location);
-            for target_location in target_locations {
-                if let Some(&distance) = distance_map.get(&target_location) {
-                    let entry = potential_targets.entry(distance).or_insert_with(|| vec![]);
-                    entry.push(target_location)
-                }
+            self.pending_turns.pop();
+            if let GameResult::Aborted = result {
+                return Ok(result);
             }
         }
 
-        if let Some(min_distance) = potential_targets.keys().min().cloned() {
-            if let Some(potential_targets) = potential_targets.remove(&min_distance) {
-                if let Some(target) = potential_targets.first() {
-                    let path = self
-                        .calc_path(&unit.location, target, &distance_map)
-                        .expect("There must no empty paths exist.");
-
-                    let new_location = path[path.len() - 1].clone();
-
-                    self.units.remove(&unit.location);
-                    self.units
-                        .insert(new_location.clone(), UnitHandle::clone(unit_ref));
+        if self.next_unit().is_none() {
+            self.pending_turns.clear();
+            self.full_rounds += 1;
+            subscriber.notify(&Event::RoundEnded {
+                round: self.full_rounds,
+            })?;
 
-                    (*unit).location = new_location.clone();
-                }
+            if self.idle_round {
+                return Ok(GameResult::Stalemate);
             }
         }
+
+        Ok(GameResult::NotYetDone)
     }
 
-    pub fn generate_distance_map(&self, location: &Location) -> BTreeMap<Location, u32> {
-        let mut distance_map = BTreeMap::new();
-        let mut waiting = HashSet::new();
+    /// Unit taking the next turn of the current round, nothing if the round is over.
+    ///
+    /// Drops units which died before their turn.
+    fn next_unit(&mut self) -> Option<UnitId> {
+        while let Some(&id) = self.pending_turns.last() {
+            if self.unit(id).alive() {
+                return Some(id);
+            }
 
-        let mut outstanding_locations = VecDeque::new();
-        outstanding_locations.push_back((location.clone(), 0u32));
+            self.pending_turns.pop();
+        }
 
-        while let Some((location, distance)) = outstanding_locations.pop_front() {
-            self.free_adjacent(&location)
-                .into_iter()
-                .filter(|adjacent| !distance_map.contains_key(adjacent))
-                .for_each(|adjacent| {
-                    if !waiting.contains(&adjacent) {
-                        waiting.insert(adjacent.clone());
-                        outstanding_locations.push_back((adjacent, distance + 1));
-                    }
-                });
+        None
+    }
 
-            waiting.remove(&location);
-            distance_map.insert(location, distance + 1);
+    /// Unit taking the next turn, before the first turn of a round it's the first in
+    /// reading-order.
+    pub fn current_unit(&self) -> Option<UnitId> {
+        match self.pending_turns.last() {
+            Some(&id) => Some(id),
+            None => self.units().next().map(|unit| unit.id),
         }
+    }
 
-        distance_map
+    pub fn full_rounds(&self) -> u32 {
+        self.full_rounds
     }
 
-    /// Calculate a path between start and destination based on a pre calculated distance map
-    /// which must be based on the start location.
-    fn calc_path(
-        &self,
-        start: &Location,
-        destination: &Location,
-        distance_map: &BTreeMap<Location, u32>,
-    ) -> Option<Vec<Location>> {
-        if !distance_map.contains_key(&start) {
-            return None;
-        }
+    /// True between the first and the last turn of a round.
+    pub fn is_mid_round(&self) -> bool {
+        !self.pending_turns.is_empty()
+    }
 
-        if !distance_map.contains_key(&destination) {
-            return None;
+    fn take_turn(
+        &mut self,
+        id: UnitId,
+        subscriber: &mut impl Subscriber,
+    ) -> io::Result<GameResult> {
+        let current_race = self.unit(id).race.clone();
+        if self.has_race_won(&current_race) {
+            return Ok(GameResult::Finished(current_race));
         }
 
-        let mut path = vec![];
-        let mut last_element_distance = Some(&u32::max_value());
+        let nearby_target = match self.get_nearby_target(id) {
+            Some(target) => Some(target),
+            None if self
+                .strategy(&current_race)
+                .hold_position(self, self.unit(id)) =>
+            {
+                None
+            }
+            None => {
+                let from = self.unit(id).location.clone();
+                self.move_unit(id);
+
+                let to = self.unit(id).location.clone();
+                if from != to {
+                    self.idle_round = false;
+                    subscriber.notify(&Event::Moved { unit: id, from, to })?;
+                }
 
-        let mut visited = HashSet::new();
-        let mut outstanding = BinaryHeap::new();
-        outstanding.push(destination.clone());
+                self.get_nearby_target(id)
+            }
+        };
 
-        while let Some(location) = outstanding.pop() {
-            let current_distance = distance_map.get(&location);
+        if let Some(target_id) = nearby_target {
+            let attack_power = self.unit(id).attack_power;
+            let target = &mut self.units[target_id.0];
 
-            let next_locations = location.adjacent();
-            if next_locations.contains(start) {
-                path.push((location, current_distance));
-                break;
+            let hit_points = target.hit_points;
+            let is_dead = target.suffer_attack(attack_power);
+            let damage = hit_points - target.hit_points;
+            if damage > 0 {
+                self.idle_round = false;
             }
 
-            while current_distance >= last_element_distance {
-                path.pop();
+            subscriber.notify(&Event::Attacked {
+                attacker: id,
+                target: target_id,
+                damage,
+                hp_left: target.hit_points,
+            })?;
+
+            *self.damage_dealt.entry(current_race.clone()).or_insert(0) += damage;
+
+            if is_dead {
+                let target = self.unit(target_id).clone();
 
-                if let Some((_, distance)) = path.last() {
-                    last_element_distance = *distance;
-                } else {
-                    last_element_distance = Some(&u32::max_value());
+                self.casualties.push(Casualty {
+                    id: target.id,
+                    race: target.race.clone(),
+                    location: target.location.clone(),
+                    round: self.full_rounds + 1,
+                });
+                let index = self.map.index(&target.location);
+                self.occupancy[index] = None;
+
+                subscriber.notify(&Event::Died {
+                    unit: target_id,
+                    round: self.full_rounds + 1,
+                })?;
+
+                if self.abort_on_elf_death && target.race.is_elf() {
+                    return Ok(GameResult::Aborted);
                 }
             }
+        }
 
-            let next_locations = next_locations
-                .into_iter()
-                .filter(|adjacent| self.map.is_free(adjacent))
-                .filter(|adjacent| !self.units.contains_key(adjacent))
-                .filter(|adjacent| !visited.contains(adjacent))
-                .filter(|adjacent| {
-                    let distance = distance_map.get(adjacent);
-                    distance < current_distance
-                })
-                .collect::<Vec<_>>();
+        Ok(GameResult::NotYetDone)
+    }
 
-            if let Some(next_location) = next_locations.first() {
-                outstanding.push(next_location.clone());
-                path.push((location.clone(), current_distance));
-            }
+    fn get_nearby_target(&self, id: UnitId) -> Option<UnitId> {
+        let unit = self.unit(id);
+
+        let targets = self
+            .map
+            .neighbors(self.map.index(&unit.location))
+            .filter_map(|adjacent| self.occupancy[adjacent])
+            .map(|other_id| self.unit(other_id))
+            .filter(|other| self.legend.is_hostile(&unit.race, &other.race))
+            .collect::<Vec<_>>();
 
-            visited.insert(location);
+        if targets.is_empty() {
+            return None;
         }
 
-        let path = path.into_iter().map(|(location, _)| location).collect();
-        Some(path)
+        // Strategies may only pick one of the enemies in range.
+        self.strategy(&unit.race)
+            .choose_target(self, unit, &targets)
+            .filter(|&target_id| targets.iter().any(|target| target.id == target_id))
     }
 
-    fn has_race_won(&self, race: Race) -> bool {
-        self.units
-            .values()
-            .all(|other_unit| other_unit.borrow().race == race)
-    }
+    /// Moves the unit a single step towards the square its strategy heads for.
+    ///
+    /// The unit takes the first step in reading-order among all shortest paths to the square.
+    fn move_unit(&mut self, id: UnitId) {
+        let mut from_unit = mem::take(&mut self.from_unit);
 
-    fn draw(&self) {
-        // clear screen and reset to top left; draw an "animation"
-        print!("{}[2J{}[H{}", 27 as char, 27 as char, self);
-        thread::sleep(Duration::from_millis(50));
-    }
+        let unit = self.unit(id);
+        let start = self.map.index(&unit.location);
+        from_unit.fill(start, self.map.num_tiles(), |index| {
+            self.free_neighbors(index)
+        });
 
-    pub fn free_adjacent(&self, location: &Location) -> Vec<Location> {
-        self.map
-            .free_adjacent(location)
+        let mut in_range = self
+            .units
+            .iter()
+            .filter(|target| target.alive() && self.legend.is_hostile(&unit.race, &target.race))
+            .flat_map(|target| self.free_neighbors(self.map.index(&target.location)))
+            .filter(|&index| from_unit.get(index).is_some())
+            .collect::<Vec<_>>();
+        in_range.sort_unstable();
+        in_range.dedup();
+
+        let destinations = in_range
             .into_iter()
-            .filter(|adjacent| !self.units.contains_key(adjacent))
-            .collect()
-    }
-}
-
-impl FromStr for Game {
-    type Err = Box<dyn Error>;
+            .filter_map(|index| {
+                Some(Destination {
+                    location: self.map.location(index),
+                    distance: from_unit.get(index)?,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Strategies may only head for one of the squares in range.
+        let destination = self
+            .strategy(&unit.race)
+            .choose_destination(self, unit, &destinations)
+            .filter(|location| {
+                destinations
+                    .iter()
+                    .any(|destination| &destination.location == location)
+            });
+
+        self.from_unit = from_unit;
+
+        let destination = match destination {
+            Some(location) => self.map.index(&location),
+            None => return,
+        };
 
-    fn from_str(input: &str) -> Result<Self> {
-        let raw_tiles = input.lines().enumerate().flat_map(move |(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(move |(x, symbol)| (Location::new(x, y), symbol))
+        let mut from_destination = mem::take(&mut self.from_destination);
+        from_destination.fill(destination, self.map.num_tiles(), |index| {
+            self.free_neighbors(index)
         });
 
-        let mut tiles = BTreeMap::new();
-        let mut units = BTreeMap::new();
+        let step = self
+            .free_neighbors(start)
+            .filter_map(|index| Some((from_destination.get(index)?, index)))
+            .min();
 
-        for (location, symbol) in raw_tiles {
-            match symbol {
-                'G' | 'E' => {
-                    let mut unit = Unit::from_char(symbol)?;
-                    unit.location = location.clone();
-                    units.insert(location.clone(), Rc::new(RefCell::new(unit)));
-                    tiles.insert(location, Tile::Floor);
-                }
-                '.' | '#' => {
-                    let tile = Tile::from_char(symbol)?;
-                    tiles.insert(location, tile);
-                }
-                _ => Err(format!("Unknown symbol discovered: {}", symbol))?,
-            }
+        self.from_destination = from_destination;
+
+        if let Some((_, step)) = step {
+            self.occupancy[start] = None;
+            self.occupancy[step] = Some(id);
+
+            self.units[id.0].location = self.map.location(step);
         }
+    }
 
-        let map = Map::new(tiles);
+    /// A race has won once no unit hostile to it is left.
+    fn has_race_won(&self, race: &Race) -> bool {
+        self.units
+            .iter()
+            .filter(|other_unit| other_unit.alive())
+            .all(|other_unit| !self.legend.is_hostile(race, &other_unit.race))
+    }
 
-        Ok(Game { map, units })
+    /// Adjacent tiles which are neither walls nor occupied by a unit.
+    fn free_neighbors(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.map.neighbors(index).filter(move |&adjacent| {
+            self.map.is_free(adjacent) && self.occupancy[adjacent].is_none()
+        })
+    }
+}
+
+impl FromStr for Game {
+    type Err = ScenarioError;
+
+    fn from_str(input: &str) -> Result<Self, ScenarioError> {
+        Game::with_legend(input, Legend::default())
     }
 }
 
@@ -317,14 +652,14 @@ impl Display for Game {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let width = self.map.size.0;
 
-        for (location, tile) in &self.map.tiles {
-            let symbol = if let Some(unit) = self.units.get(location) {
-                unit.borrow().to_string()
+        for (index, tile) in self.map.tiles() {
+            let symbol = if let Some(id) = self.occupancy[index] {
+                self.unit(id).to_string()
             } else {
                 tile.to_string()
             };
 
-            if (location.x + 1) == width {
+            if (index % width + 1) == width {
                 writeln!(f, "{}", symbol)?;
             } else {
                 write!(f, "{}", symbol)?;
@@ -334,3 +669,245 @@ impl Display for Game {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Result;
+
+    const COMBAT: &str = "#######\n\
+                          #.G...#\n\
+                          #...EG#\n\
+                          #.#.#G#\n\
+                          #..G#E#\n\
+                          #.....#\n\
+                          #######";
+
+    #[test]
+    fn reject_invalid_maps() {
+        let ragged = "#####\n#E.G#\n####";
+        assert!(matches!(
+            Game::from_str(ragged),
+            Err(ScenarioError::RaggedMap {
+                line: 3,
+                expected: 5,
+                found: 4
+            })
+        ));
+
+        let unknown = "#####\n#E.X#\n#####";
+        assert!(matches!(
+            Game::from_str(unknown),
+            Err(ScenarioError::UnknownSymbol { symbol: 'X', .. })
+        ));
+
+        let single_faction = "#####\n#E.E#\n#####";
+        assert!(matches!(
+            Game::from_str(single_faction),
+            Err(ScenarioError::TooFewFactions)
+        ));
+
+        let mut legend = Legend::default();
+        legend.units.insert('#', legend.units[&'G'].clone());
+        assert!(matches!(
+            Game::with_legend("#####\n#E.G#\n#####", legend),
+            Err(ScenarioError::ReservedSymbol('#'))
+        ));
+    }
+
+    #[test]
+    fn detect_stalemates() -> Result<()> {
+        let walled_off = "#######\n\
+                          #E#.G.#\n\
+                          ###...#\n\
+                          #..G..#\n\
+                          #######";
+
+        let mut game = Game::from_str(walled_off)?;
+        assert!(matches!(
+            game.run(),
+            Err(BattleError::Stalemate { full_rounds: 1 })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn clones_are_independent() -> Result<()> {
+        fn assert_send<T: Send>(_: &T) {}
+
+        let game = Game::from_str(COMBAT)?;
+        let mut snapshot = game.clone();
+        assert_send(&snapshot);
+
+        let report = snapshot.run()?;
+        assert!(!report.casualties.is_empty());
+
+        assert_eq!(6, game.units().count());
+        assert!(game.units().all(|unit| unit.hit_points == 200));
+        assert_eq!(COMBAT, game.to_string().trim_end());
+
+        for casualty in &report.casualties {
+            assert!(!snapshot.unit(casualty.id).alive());
+            assert!(game.unit(casualty.id).alive());
+        }
+
+        Ok(())
+    }
+
+    fn assert_battle(
+        initial_map: &str,
+        full_rounds: u32,
+        hit_points: &[u32],
+        final_map: &str,
+    ) -> Result<()> {
+        let mut game = Game::from_str(initial_map)?;
+        let report = game.run()?;
+
+        assert_eq!(full_rounds, report.full_rounds);
+        assert_eq!(
+            hit_points,
+            report
+                .survivors
+                .iter()
+                .map(|survivor| survivor.hit_points)
+                .collect::<Vec<_>>()
+                .as_slice()
+        );
+        assert_eq!(hit_points.iter().sum::<u32>(), report.hit_points_left);
+        assert_eq!(full_rounds * report.hit_points_left, report.outcome);
+        assert_eq!(final_map, game.to_string().trim_end());
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_battle_1() -> Result<()> {
+        assert_battle(
+            COMBAT,
+            47,
+            &[200, 131, 59, 200],
+            "#######\n\
+             #G....#\n\
+             #.G...#\n\
+             #.#.#G#\n\
+             #...#.#\n\
+             #....G#\n\
+             #######",
+        )
+    }
+
+    #[test]
+    fn example_battle_2() -> Result<()> {
+        assert_battle(
+            "#######\n\
+             #G..#E#\n\
+             #E#E.E#\n\
+             #G.##.#\n\
+             #...#E#\n\
+             #...E.#\n\
+             #######",
+            37,
+            &[200, 197, 185, 200, 200],
+            "#######\n\
+             #...#E#\n\
+             #E#...#\n\
+             #.E##.#\n\
+             #E..#E#\n\
+             #.....#\n\
+             #######",
+        )
+    }
+
+    #[test]
+    fn example_battle_3() -> Result<()> {
+        assert_battle(
+            "#######\n\
+             #E..EG#\n\
+             #.#G.E#\n\
+             #E.##E#\n\
+             #G..#.#\n\
+             #..E#.#\n\
+             #######",
+            46,
+            &[164, 197, 200, 98, 200],
+            "#######\n\
+             #.E.E.#\n\
+             #.#E..#\n\
+             #E.##.#\n\
+             #.E.#.#\n\
+             #...#.#\n\
+             #######",
+        )
+    }
+
+    #[test]
+    fn example_battle_4() -> Result<()> {
+        assert_battle(
+            "#######\n\
+             #E.G#.#\n\
+             #.#G..#\n\
+             #G.#.G#\n\
+             #G..#.#\n\
+             #...E.#\n\
+             #######",
+            35,
+            &[200, 98, 200, 95, 200],
+            "#######\n\
+             #G.G#.#\n\
+             #.#G..#\n\
+             #..#..#\n\
+             #...#G#\n\
+             #...G.#\n\
+             #######",
+        )
+    }
+
+    #[test]
+    fn example_battle_5() -> Result<()> {
+        assert_battle(
+            "#######\n\
+             #.E...#\n\
+             #.#..G#\n\
+             #.###.#\n\
+             #E#G#G#\n\
+             #...#G#\n\
+             #######",
+            54,
+            &[200, 98, 38, 200],
+            "#######\n\
+             #.....#\n\
+             #.#G..#\n\
+             #.###.#\n\
+             #.#.#.#\n\
+             #G.G#G#\n\
+             #######",
+        )
+    }
+
+    #[test]
+    fn example_battle_6() -> Result<()> {
+        assert_battle(
+            "#########\n\
+             #G......#\n\
+             #.E.#...#\n\
+             #..##..G#\n\
+             #...##..#\n\
+             #...#...#\n\
+             #.G...G.#\n\
+             #.....G.#\n\
+             #########",
+            20,
+            &[137, 200, 200, 200, 200],
+            "#########\n\
+             #.G.....#\n\
+             #G.G#...#\n\
+             #.G##...#\n\
+             #...##..#\n\
+             #.G.#...#\n\
+             #.......#\n\
+             #.......#\n\
+             #########",
+        )
+    }
+}
@@ -0,0 +1,42 @@
+pub mod error;
+pub mod events;
+pub mod game;
+pub mod location;
+pub mod map;
+pub mod render;
+pub mod report;
+pub mod scenario;
+pub mod search;
+pub mod stepper;
+pub mod strategy;
+pub mod tile;
+pub mod tournament;
+pub mod unit;
+
+use std::str::FromStr;
+
+use crate::game::Game;
+
+pub use aoc_common::Result;
+use aoc_common::{Answer, Input, Solution};
+
+pub struct Puzzle {
+    game: Game,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self> {
+        let game = Game::from_str(input.as_str())?;
+        Ok(Self { game })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.clone().run()?.outcome.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let minimal_attack_power = search::parallel_sweep(&self.game)?;
+
+        Ok(minimal_attack_power.report.outcome.into())
+    }
+}
@@ -1,7 +1,9 @@
+use serde::{Deserialize, Serialize};
+
 // Here the order of the struct member is important as it
 // decides how partial is derived and we want rows to be more
 // important than columns.
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Clone)]
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Clone, Serialize, Deserialize)]
 pub struct Location {
     pub y: usize,
     pub x: usize,
@@ -12,13 +14,8 @@ impl Location {
         Location { y, x }
     }
 
-    /// Returns adjacent locations in reading-order (top-to-bottom; left-to-right).
-    pub fn adjacent(&self) -> Vec<Location> {
-        vec![
-            Location::new(self.x, self.y - 1),
-            Location::new(self.x - 1, self.y),
-            Location::new(self.x + 1, self.y),
-            Location::new(self.x, self.y + 1),
-        ]
+    /// Number of steps between both locations, ignoring any obstacles.
+    pub fn distance(&self, other: &Location) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
     }
 }
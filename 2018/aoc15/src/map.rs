@@ -1,40 +1,59 @@
-use std::collections::BTreeMap;
-
 use crate::{location::Location, tile::Tile};
 
+/// Row-major grid of all tiles, addressed by their index.
 #[derive(Debug, Clone)]
 pub struct Map {
     pub size: (usize, usize),
-    pub tiles: BTreeMap<Location, Tile>,
+    tiles: Vec<Tile>,
+    /// Index offsets of the adjacent tiles in reading-order (top-to-bottom; left-to-right).
+    neighbor_offsets: [isize; 4],
 }
 
 impl Map {
-    pub fn new(tiles: BTreeMap<Location, Tile>) -> Self {
-        let width = tiles.keys().map(|location| location.x).max().unwrap_or(0);
-        let height = tiles.keys().map(|location| location.y).max().unwrap_or(0);
-        let size = (width + 1, height + 1);
+    /// Expects `width * height` tiles, ordered row by row.
+    pub fn new(width: usize, height: usize, tiles: Vec<Tile>) -> Self {
+        debug_assert_eq!(width * height, tiles.len());
+
+        let row = width as isize;
+        let neighbor_offsets = [-row, -1, 1, row];
 
-        Map { size, tiles }
+        Map {
+            size: (width, height),
+            tiles,
+            neighbor_offsets,
+        }
     }
 
-    pub fn free_adjacent(&self, location: &Location) -> Vec<Location> {
-        location
-            .adjacent()
-            .into_iter()
-            .filter(|location| {
-                if let Some(tile) = self.tiles.get(location) {
-                    tile.is_free()
-                } else {
-                    false
-                }
-            })
-            .collect()
+    pub fn num_tiles(&self) -> usize {
+        self.tiles.len()
     }
 
-    pub fn is_free(&self, location: &Location) -> bool {
-        match self.tiles.get(location) {
-            Some(Tile::Floor) => true,
-            _ => false,
-        }
+    pub fn tiles(&self) -> impl Iterator<Item = (usize, &Tile)> {
+        self.tiles.iter().enumerate()
+    }
+
+    pub fn index(&self, location: &Location) -> usize {
+        location.y * self.size.0 + location.x
+    }
+
+    pub fn location(&self, index: usize) -> Location {
+        Location::new(index % self.size.0, index / self.size.0)
+    }
+
+    /// Indices of the tiles adjacent to the given one in reading-order.
+    pub fn neighbors(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let (width, height) = self.size;
+        let (x, y) = (index % width, index / width);
+        let inside = [y > 0, x > 0, x + 1 < width, y + 1 < height];
+
+        self.neighbor_offsets
+            .iter()
+            .zip(inside)
+            .filter(|&(_, inside)| inside)
+            .map(move |(offset, _)| (index as isize + offset) as usize)
+    }
+
+    pub fn is_free(&self, index: usize) -> bool {
+        self.tiles[index].is_free()
     }
 }
@@ -0,0 +1,201 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use crate::{game::Game, report::BattleReport};
+
+/// Observes a running game and gets notified after every round.
+pub trait Renderer {
+    /// Called with the initial state (round `0`) and after every full round.
+    fn render(&mut self, round: u32, game: &Game) -> io::Result<()>;
+
+    /// Called with the final state once one race has won the fight.
+    fn finish(&mut self, report: &BattleReport, game: &Game) -> io::Result<()> {
+        let _ = (report, game);
+        Ok(())
+    }
+}
+
+/// Runs the game headless.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NullRenderer;
+
+impl Renderer for NullRenderer {
+    fn render(&mut self, _: u32, _: &Game) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Animates the fight inside the terminal, waiting `frame_delay` between two rounds.
+#[derive(Debug, Clone, Copy)]
+pub struct TerminalRenderer {
+    pub frame_delay: Duration,
+}
+
+impl TerminalRenderer {
+    pub fn new(frame_delay: Duration) -> Self {
+        TerminalRenderer { frame_delay }
+    }
+
+    /// Plays back the frames of a recording created by the [`Recorder`].
+    pub fn replay(&self, recording: &str) -> io::Result<()> {
+        for frame in recording.split("\n\n").filter(|frame| !frame.is_empty()) {
+            self.draw(frame)?;
+        }
+
+        Ok(())
+    }
+
+    fn draw(&self, frame: &str) -> io::Result<()> {
+        let mut stdout = io::stdout();
+
+        // clear screen and reset to top left; draw an "animation"
+        write!(stdout, "{}[2J{}[H{}", 27 as char, 27 as char, frame)?;
+        stdout.flush()?;
+
+        thread::sleep(self.frame_delay);
+        Ok(())
+    }
+}
+
+impl Default for TerminalRenderer {
+    fn default() -> Self {
+        TerminalRenderer::new(Duration::from_millis(50))
+    }
+}
+
+impl Renderer for TerminalRenderer {
+    fn render(&mut self, round: u32, game: &Game) -> io::Result<()> {
+        self.draw(&frame(round, game))
+    }
+
+    fn finish(&mut self, report: &BattleReport, game: &Game) -> io::Result<()> {
+        self.draw(&final_frame(report, game))
+    }
+}
+
+/// Writes every round, including the hit points of all units, for a later replay.
+///
+/// Frames are separated by an empty line.
+pub struct Recorder<WriterT: Write> {
+    writer: WriterT,
+}
+
+impl Recorder<BufWriter<File>> {
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Recorder::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<WriterT: Write> Recorder<WriterT> {
+    pub fn new(writer: WriterT) -> Self {
+        Recorder { writer }
+    }
+
+    pub fn into_inner(self) -> WriterT {
+        self.writer
+    }
+}
+
+impl<WriterT: Write> Renderer for Recorder<WriterT> {
+    fn render(&mut self, round: u32, game: &Game) -> io::Result<()> {
+        writeln!(self.writer, "{}", frame(round, game))
+    }
+
+    fn finish(&mut self, report: &BattleReport, game: &Game) -> io::Result<()> {
+        writeln!(self.writer, "{}", final_frame(report, game))?;
+        self.writer.flush()
+    }
+}
+
+fn frame(round: u32, game: &Game) -> String {
+    format!("Round {}\n{}", round, annotated_map(game))
+}
+
+fn final_frame(report: &BattleReport, game: &Game) -> String {
+    format!(
+        "Combat ends after {} full rounds\n{}{} wins with {} total hit points left\n",
+        report.full_rounds,
+        annotated_map(game),
+        report.winner,
+        report.hit_points_left
+    )
+}
+
+/// Lists the hit points of the units next to each row of the map.
+pub(crate) fn annotated_map(game: &Game) -> String {
+    let map = game.to_string();
+
+    let mut annotated = String::new();
+    for (y, row) in map.lines().enumerate() {
+        let hit_points = game
+            .units()
+            .filter(|unit| unit.location.y == y)
+            .map(|unit| format!("{}({})", unit, unit.hit_points))
+            .collect::<Vec<_>>();
+
+        annotated += row;
+        if !hit_points.is_empty() {
+            annotated += "   ";
+            annotated += &hit_points.join(", ");
+        }
+        annotated += "\n";
+    }
+
+    annotated
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const COMBAT: &str = "#######\n\
+                          #G..#E#\n\
+                          #E#E.E#\n\
+                          #G.##.#\n\
+                          #...#E#\n\
+                          #...E.#\n\
+                          #######";
+
+    #[test]
+    fn record_every_round() -> crate::Result<()> {
+        let mut game = Game::from_str(COMBAT)?;
+        let mut recorder = Recorder::new(vec![]);
+
+        let report = game.run_with(&mut recorder)?;
+        let recording = String::from_utf8(recorder.into_inner())?;
+
+        let frames = recording
+            .split("\n\n")
+            .filter(|frame| !frame.is_empty())
+            .collect::<Vec<_>>();
+        assert_eq!(report.full_rounds as usize + 2, frames.len());
+
+        assert_eq!(
+            "Round 0\n\
+             #######\n\
+             #G..#E#   G(200), E(200)\n\
+             #E#E.E#   E(200), E(200), E(200)\n\
+             #G.##.#   G(200)\n\
+             #...#E#   E(200)\n\
+             #...E.#   E(200)\n\
+             #######",
+            frames[0]
+        );
+
+        let last_frame = frames[frames.len() - 1];
+        assert!(last_frame.starts_with(&format!(
+            "Combat ends after {} full rounds\n",
+            report.full_rounds
+        )));
+        assert!(last_frame.contains(&format!("{} wins with", report.winner)));
+
+        Ok(())
+    }
+}
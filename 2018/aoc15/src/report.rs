@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    location::Location,
+    unit::{Race, UnitId},
+};
+
+/// Summary of a finished battle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BattleReport {
+    /// Rounds completed before the last unit of the losing race died.
+    pub full_rounds: u32,
+    pub winner: Race,
+    /// Number of full rounds multiplied with the sum of the hit points left.
+    pub outcome: u32,
+    pub hit_points_left: u32,
+    /// Units alive at the end of the battle, in reading-order.
+    pub survivors: Vec<Survivor>,
+    /// Units which died during the battle, in order of their death.
+    pub casualties: Vec<Casualty>,
+    /// Total hit points taken from the enemies by all units of a race.
+    pub damage_dealt: BTreeMap<Race, u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Survivor {
+    pub id: UnitId,
+    pub race: Race,
+    pub location: Location,
+    pub hit_points: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Casualty {
+    pub id: UnitId,
+    pub race: Race,
+    /// Location the unit died at.
+    pub location: Location,
+    /// Round the unit died in, starting with round `1`.
+    pub round: u32,
+}
+
+impl BattleReport {
+    pub fn casualties_of<'a>(&'a self, race: &'a Race) -> impl Iterator<Item = &'a Casualty> {
+        self.casualties
+            .iter()
+            .filter(move |casualty| &casualty.race == race)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::game::Game;
+
+    const COMBAT: &str = "#######\n\
+                          #.G...#\n\
+                          #...EG#\n\
+                          #.#.#G#\n\
+                          #..G#E#\n\
+                          #.....#\n\
+                          #######";
+
+    #[test]
+    fn account_for_every_unit() -> crate::Result<()> {
+        let report = Game::from_str(COMBAT)?.run()?;
+
+        assert_eq!(report.full_rounds * report.hit_points_left, report.outcome);
+        assert_eq!(6, report.survivors.len() + report.casualties.len());
+        assert!(report
+            .survivors
+            .iter()
+            .all(|survivor| survivor.race == report.winner));
+
+        let hit_points_lost = report.casualties.len() as u32 * 200
+            + report
+                .survivors
+                .iter()
+                .map(|survivor| 200 - survivor.hit_points)
+                .sum::<u32>();
+        assert_eq!(hit_points_lost, report.damage_dealt.values().sum::<u32>());
+
+        Ok(())
+    }
+}
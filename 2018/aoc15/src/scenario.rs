@@ -0,0 +1,180 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::ScenarioError,
+    game::Game,
+    location::Location,
+    unit::{Race, UnitTemplate},
+};
+
+/// Maps the symbols of a map to the units they represent and decides which races fight each other.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Legend {
+    #[serde(default = "Legend::puzzle_units")]
+    pub units: BTreeMap<char, UnitTemplate>,
+    /// Pairs of races which don't attack each other, all other races are hostile.
+    #[serde(default)]
+    pub alliances: Vec<(Race, Race)>,
+}
+
+/// Battle described by a TOML file, e.g.
+///
+/// ```toml
+/// map = """
+/// #######
+/// #E..GD#
+/// #######
+/// """
+/// alliances = [["Elf", "Dwarf"]]
+///
+/// [units.D]
+/// race = "Dwarf"
+/// hit_points = 300
+///
+/// [[overrides]]
+/// x = 1
+/// y = 1
+/// attack_power = 10
+/// ```
+///
+/// Symbols without a unit template of their own are the elves (`E`) and goblins (`G`) of the puzzle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scenario {
+    pub map: String,
+    #[serde(flatten)]
+    pub legend: Legend,
+    /// Adjustments of single units, identified by their initial location.
+    #[serde(default)]
+    pub overrides: Vec<UnitOverride>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnitOverride {
+    pub x: usize,
+    pub y: usize,
+    pub hit_points: Option<u32>,
+    pub attack_power: Option<u32>,
+}
+
+impl Legend {
+    pub fn template(&self, symbol: char) -> Option<&UnitTemplate> {
+        self.units.get(&symbol)
+    }
+
+    pub fn is_hostile(&self, race: &Race, other: &Race) -> bool {
+        race != other
+            && !self.alliances.iter().any(|(first, second)| {
+                (first == race && second == other) || (first == other && second == race)
+            })
+    }
+
+    /// Elves and goblins with 200 hit points and an attack power of 3.
+    fn puzzle_units() -> BTreeMap<char, UnitTemplate> {
+        let mut units = BTreeMap::new();
+        units.insert('E', UnitTemplate::new(Race::elf()));
+        units.insert('G', UnitTemplate::new(Race::goblin()));
+
+        units
+    }
+}
+
+impl Default for Legend {
+    fn default() -> Self {
+        Legend {
+            units: Legend::puzzle_units(),
+            alliances: vec![],
+        }
+    }
+}
+
+impl Scenario {
+    pub fn game(&self) -> Result<Game, ScenarioError> {
+        let mut game = Game::with_legend(&self.map, self.legend.clone())?;
+
+        for unit in &self.overrides {
+            game.adjust_unit(
+                &Location::new(unit.x, unit.y),
+                unit.hit_points,
+                unit.attack_power,
+            )?;
+        }
+
+        Ok(game)
+    }
+}
+
+impl FromStr for Scenario {
+    type Err = ScenarioError;
+
+    fn from_str(input: &str) -> Result<Self, ScenarioError> {
+        let mut scenario = toml::from_str::<Scenario>(input)?;
+
+        // Custom symbols extend the ones of the puzzle.
+        for (symbol, template) in Legend::puzzle_units() {
+            scenario.legend.units.entry(symbol).or_insert(template);
+        }
+
+        Ok(scenario)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Result;
+
+    const SCENARIO: &str = r#"
+map = """
+#########
+#E..G..D#
+#########
+"""
+alliances = [["Elf", "Dwarf"]]
+
+[units.D]
+race = "Dwarf"
+hit_points = 300
+
+[[overrides]]
+x = 1
+y = 1
+attack_power = 10
+"#;
+
+    #[test]
+    fn read_scenario() -> Result<()> {
+        let scenario = Scenario::from_str(SCENARIO)?;
+
+        let dwarf = Race::new("Dwarf");
+        assert!(!scenario.legend.is_hostile(&Race::elf(), &dwarf));
+        assert!(scenario.legend.is_hostile(&Race::goblin(), &dwarf));
+        assert!(scenario.legend.is_hostile(&Race::elf(), &Race::goblin()));
+
+        let game = scenario.game()?;
+        let units = game
+            .units()
+            .map(|unit| (unit.race.name(), unit.hit_points, unit.attack_power))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![("Elf", 200, 10), ("Goblin", 200, 3), ("Dwarf", 300, 3)],
+            units
+        );
+
+        // Allies win together, the winner is the one which noticed it first.
+        let report = scenario.game()?.run()?;
+        assert!(!scenario.legend.is_hostile(&Race::elf(), &report.winner));
+        assert_eq!(
+            vec![Race::elf(), dwarf],
+            report
+                .survivors
+                .iter()
+                .map(|survivor| survivor.race.clone())
+                .collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+use std::cmp;
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::SearchError, game::Game, report::BattleReport, unit::Race};
+
+/// Elves start with an attack power of 3, cheating begins with the next higher one.
+pub const MIN_ELF_ATTACK_POWER: u32 = 4;
+
+/// Smallest elf attack power which lets the elves win without a single loss.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MinimalAttackPower {
+    pub elf_attack_power: u32,
+    pub report: BattleReport,
+}
+
+/// Simulates a copy of the game, returns nothing as soon as the first elf dies or in a stalemate.
+pub fn simulate(game: &Game, elf_attack_power: u32) -> Option<BattleReport> {
+    let mut game = game.clone();
+    game.set_elf_attack_power(elf_attack_power);
+
+    game.run_without_elf_losses()
+}
+
+/// Finds an attack power without elf losses with as few simulations as possible.
+///
+/// Only a heuristic: it assumes that elves never suffer losses with more attack power once they
+/// won without any. The battle doesn't guarantee that, more attack power changes the order of
+/// the moves and may cost an elf which a lower one saves. The result can be higher than the
+/// minimal attack power then, use [`parallel_sweep`] for that.
+pub fn binary_search(game: &Game) -> Result<MinimalAttackPower, SearchError> {
+    check_elves(game)?;
+
+    let max_elf_attack_power = max_elf_attack_power(game);
+    let mut lower = MIN_ELF_ATTACK_POWER;
+    let mut upper = max_elf_attack_power;
+    let mut report = simulate(game, upper).ok_or(SearchError::NoSolution {
+        max_elf_attack_power,
+    })?;
+
+    while lower < upper {
+        let elf_attack_power = lower + (upper - lower) / 2;
+
+        match simulate(game, elf_attack_power) {
+            Some(lossless_report) => {
+                upper = elf_attack_power;
+                report = lossless_report;
+            }
+            None => lower = elf_attack_power + 1,
+        }
+    }
+
+    Ok(MinimalAttackPower {
+        elf_attack_power: upper,
+        report,
+    })
+}
+
+/// Finds the minimal attack power by simulating batches of ascending attack powers concurrently.
+///
+/// Unlike the binary search every attack power below the result is checked.
+pub fn parallel_sweep(game: &Game) -> Result<MinimalAttackPower, SearchError> {
+    check_elves(game)?;
+
+    let batch_size = cmp::max(rayon::current_num_threads(), 1) as u32;
+    let max_elf_attack_power = max_elf_attack_power(game);
+
+    let mut batch_start = MIN_ELF_ATTACK_POWER;
+    loop {
+        let batch_end = cmp::min(
+            batch_start.saturating_add(batch_size - 1),
+            max_elf_attack_power,
+        );
+
+        let games = (batch_start..=batch_end)
+            .map(|elf_attack_power| {
+                let mut game = game.clone();
+                game.set_elf_attack_power(elf_attack_power);
+                (elf_attack_power, game)
+            })
+            .collect::<Vec<_>>();
+
+        let minimal_attack_power = games
+            .into_par_iter()
+            .filter_map(|(elf_attack_power, mut game)| {
+                let report = game.run_without_elf_losses()?;
+                Some(MinimalAttackPower {
+                    elf_attack_power,
+                    report,
+                })
+            })
+            .min_by_key(|result| result.elf_attack_power);
+
+        if let Some(minimal_attack_power) = minimal_attack_power {
+            return Ok(minimal_attack_power);
+        }
+
+        if batch_end == max_elf_attack_power {
+            return Err(SearchError::NoSolution {
+                max_elf_attack_power,
+            });
+        }
+
+        batch_start = batch_end + 1;
+    }
+}
+
+/// With this attack power elves kill every enemy with a single hit, more can't change the battle.
+pub fn max_elf_attack_power(game: &Game) -> u32 {
+    let elf = Race::elf();
+
+    game.units()
+        .filter(|unit| game.legend().is_hostile(&elf, &unit.race))
+        .map(|unit| unit.hit_points)
+        .fold(MIN_ELF_ATTACK_POWER, cmp::max)
+}
+
+fn check_elves(game: &Game) -> Result<(), SearchError> {
+    match game.count_elves() {
+        0 => Err(SearchError::NoElves),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::location::Location;
+
+    const COMBAT: &str = "#######\n\
+                          #.G...#\n\
+                          #...EG#\n\
+                          #.#.#G#\n\
+                          #..G#E#\n\
+                          #.....#\n\
+                          #######";
+
+    #[test]
+    fn find_lossless_attack_powers() -> crate::Result<()> {
+        let game = Game::from_str(COMBAT)?;
+
+        let swept = parallel_sweep(&game)?;
+        assert!(swept.report.casualties_of(&Race::elf()).next().is_none());
+        assert!((MIN_ELF_ATTACK_POWER..swept.elf_attack_power)
+            .all(|power| simulate(&game, power).is_none()));
+
+        // The binary search may skip the minimum, but never returns an attack power with losses.
+        let searched = binary_search(&game)?;
+        assert!(searched.elf_attack_power >= swept.elf_attack_power);
+        assert!(searched.report.casualties_of(&Race::elf()).next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fail_without_solution() -> crate::Result<()> {
+        let walled_off = "#######\n\
+                          #E#.G.#\n\
+                          ###...#\n\
+                          #..G..#\n\
+                          #######";
+        let game = Game::from_str(walled_off)?;
+
+        let no_solution = SearchError::NoSolution {
+            max_elf_attack_power: 200,
+        };
+        assert_eq!(Err(no_solution.clone()), binary_search(&game));
+        assert_eq!(Err(no_solution), parallel_sweep(&game));
+
+        Ok(())
+    }
+
+    #[test]
+    fn search_beyond_default_hit_points() -> crate::Result<()> {
+        // The goblin kills the elf with its first hit, so the elf has to strike first and kill it.
+        let mut game = Game::from_str("####\n#EG#\n####")?;
+        game.adjust_unit(&Location::new(2, 1), Some(300), Some(200))?;
+
+        assert_eq!(300, max_elf_attack_power(&game));
+        assert_eq!(300, binary_search(&game)?.elf_attack_power);
+        assert_eq!(300, parallel_sweep(&game)?.elf_attack_power);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,309 @@
+use std::{
+    error::Error,
+    io::{BufRead, Write},
+    str::FromStr,
+};
+
+use crate::{
+    game::{Game, GameResult},
+    location::Location,
+    render, Result,
+};
+
+const HELP: &str = "[t]urn  [r]ound  [g]oto <round>  [b]ack  [c]ursor <x> <y>  w/a/s/d  [q]uit";
+
+/// Single line of input to the stepper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Lets the next unit take its turn, also triggered by an empty line.
+    Turn,
+    /// Plays the rest of the current round.
+    Round,
+    /// Plays or rewinds the game to the start of the given round.
+    Goto(u32),
+    /// Rewinds the last step.
+    Back,
+    /// Places the cursor on the given location.
+    Cursor(Location),
+    /// Moves the cursor by a single tile.
+    MoveCursor(isize, isize),
+    Quit,
+}
+
+/// Plays a battle step by step, remembers the game before every step to rewind it.
+#[derive(Debug, Clone)]
+pub struct Stepper {
+    game: Game,
+    /// Games before each step, the latest last.
+    snapshots: Vec<Game>,
+    cursor: Location,
+    /// Final result once the battle is over.
+    over: Option<GameResult>,
+}
+
+impl Stepper {
+    /// Places the cursor on the first unit.
+    pub fn new(game: Game) -> Self {
+        let cursor = game
+            .units()
+            .next()
+            .map_or(Location::new(0, 0), |unit| unit.location.clone());
+
+        Stepper {
+            game,
+            snapshots: vec![],
+            cursor,
+            over: None,
+        }
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Reads commands line by line and redraws the game after each one until the input ends.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> Result<()> {
+        let mut status = String::new();
+        let mut lines = input.lines();
+
+        loop {
+            // clear screen and reset to top left
+            write!(output, "{}[2J{}[H{}", 27 as char, 27 as char, self.view())?;
+            writeln!(output, "{}\n{}", status, HELP)?;
+            write!(output, "> ")?;
+            output.flush()?;
+
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => return Ok(()),
+            };
+
+            status = match line.parse() {
+                Ok(Command::Quit) => return Ok(()),
+                Ok(command) => match self.execute(&command) {
+                    Ok(()) => String::new(),
+                    Err(error) => error.to_string(),
+                },
+                Err(error) => error.to_string(),
+            };
+        }
+    }
+
+    pub fn execute(&mut self, command: &Command) -> Result<()> {
+        match command {
+            Command::Turn => self.step(Game::next_unit_turn),
+            Command::Round => self.step(Game::next_turn),
+            Command::Goto(round) => self.goto(*round)?,
+            Command::Back => {
+                self.game = self.snapshots.pop().ok_or("Nothing left to rewind.")?;
+                self.over = None;
+            }
+            Command::Cursor(location) => {
+                let (width, height) = self.game.size();
+                if location.x >= width || location.y >= height {
+                    Err(format!("{:?} lies outside of the map.", location))?;
+                }
+
+                self.cursor = location.clone();
+            }
+            Command::MoveCursor(dx, dy) => {
+                let (width, height) = self.game.size();
+                let x = (self.cursor.x as isize + dx).clamp(0, width as isize - 1);
+                let y = (self.cursor.y as isize + dy).clamp(0, height as isize - 1);
+
+                self.cursor = Location::new(x as usize, y as usize);
+            }
+            Command::Quit => {}
+        }
+
+        Ok(())
+    }
+
+    /// Map with the hit points of all units beside it, followed by the unit under the cursor.
+    pub fn view(&self) -> String {
+        let mut view = match &self.over {
+            Some(GameResult::Finished(winner)) => format!(
+                "{} wins after {} full rounds\n",
+                winner,
+                self.game.full_rounds()
+            ),
+            Some(_) => format!("Stalemate after {} full rounds\n", self.game.full_rounds()),
+            None => format!(
+                "Round {}{}\n",
+                self.game.full_rounds() + 1,
+                self.next_turn()
+            ),
+        };
+
+        for (y, row) in render::annotated_map(&self.game).lines().enumerate() {
+            if y != self.cursor.y {
+                view += row;
+            } else {
+                // highlight the cursor through reversed colors
+                for (x, symbol) in row.chars().enumerate() {
+                    if x == self.cursor.x {
+                        view += &format!("{}[7m{}{}[0m", 27 as char, symbol, 27 as char);
+                    } else {
+                        view.push(symbol);
+                    }
+                }
+            }
+            view += "\n";
+        }
+
+        let (x, y) = (self.cursor.x, self.cursor.y);
+        view += &match self.game.unit_at(&self.cursor) {
+            Some(unit) => format!(
+                "Cursor ({}, {}): {} #{} with {} HP and an attack power of {}\n",
+                x, y, unit.race, unit.id.0, unit.hit_points, unit.attack_power
+            ),
+            None => format!("Cursor ({}, {}): no unit\n", x, y),
+        };
+
+        view
+    }
+
+    fn next_turn(&self) -> String {
+        match self.game.current_unit() {
+            Some(id) => {
+                let unit = self.game.unit(id);
+                format!(
+                    ", next turn: {} #{} at ({}, {})",
+                    unit.race, unit.id.0, unit.location.x, unit.location.y
+                )
+            }
+            None => String::new(),
+        }
+    }
+
+    fn step(&mut self, step: impl FnOnce(&mut Game) -> GameResult) {
+        if self.over.is_some() {
+            return;
+        }
+
+        self.snapshots.push(self.game.clone());
+        match step(&mut self.game) {
+            GameResult::NotYetDone => {}
+            result => self.over = Some(result),
+        }
+    }
+
+    fn goto(&mut self, round: u32) -> Result<()> {
+        // Rewind to the latest snapshot at the start of the round or before it.
+        while round < self.game.full_rounds()
+            || (round == self.game.full_rounds() && self.game.is_mid_round())
+        {
+            self.game = self
+                .snapshots
+                .pop()
+                .ok_or("The round lies before the first snapshot.")?;
+            self.over = None;
+        }
+
+        while self.over.is_none() && self.game.full_rounds() < round {
+            self.step(Game::next_turn);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Command {
+    type Err = Box<dyn Error>;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+
+        let command = match words.as_slice() {
+            [] | ["t"] => Command::Turn,
+            ["r"] => Command::Round,
+            ["g", round] => Command::Goto(round.parse()?),
+            ["b"] => Command::Back,
+            ["c", x, y] => Command::Cursor(Location::new(x.parse()?, y.parse()?)),
+            ["w"] => Command::MoveCursor(0, -1),
+            ["a"] => Command::MoveCursor(-1, 0),
+            ["s"] => Command::MoveCursor(0, 1),
+            ["d"] => Command::MoveCursor(1, 0),
+            ["q"] => Command::Quit,
+            _ => Err(format!("Unknown command: {}", line))?,
+        };
+
+        Ok(command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMBAT: &str = "#######\n\
+                          #.G...#\n\
+                          #...EG#\n\
+                          #.#.#G#\n\
+                          #..G#E#\n\
+                          #.....#\n\
+                          #######";
+
+    fn hit_points(game: &Game) -> Vec<u32> {
+        game.units().map(|unit| unit.hit_points).collect()
+    }
+
+    #[test]
+    fn step_and_rewind() -> Result<()> {
+        let mut stepper = Stepper::new(Game::from_str(COMBAT)?);
+
+        stepper.execute(&"t".parse()?)?;
+        assert!(stepper.game().is_mid_round());
+        assert_eq!(0, stepper.game().full_rounds());
+
+        stepper.execute(&"r".parse()?)?;
+        assert!(!stepper.game().is_mid_round());
+        assert_eq!(1, stepper.game().full_rounds());
+
+        let mut reference = Game::from_str(COMBAT)?;
+        reference.next_turn();
+        reference.next_turn();
+        reference.next_turn();
+
+        stepper.execute(&"g 3".parse()?)?;
+        assert_eq!(reference.to_string(), stepper.game().to_string());
+        assert_eq!(hit_points(&reference), hit_points(stepper.game()));
+
+        stepper.execute(&"g 47".parse()?)?;
+        stepper.execute(&"r".parse()?)?;
+        assert!(stepper
+            .view()
+            .starts_with("Goblin wins after 47 full rounds\n"));
+
+        stepper.execute(&"g 1".parse()?)?;
+        assert_eq!(1, stepper.game().full_rounds());
+        assert!(!stepper.game().is_mid_round());
+
+        stepper.execute(&"b".parse()?)?;
+        stepper.execute(&"b".parse()?)?;
+        assert_eq!(COMBAT, stepper.game().to_string().trim_end());
+        assert!(stepper.execute(&Command::Back).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn inspect_units() -> Result<()> {
+        let mut stepper = Stepper::new(Game::from_str(COMBAT)?);
+        stepper.execute(&"r".parse()?)?;
+
+        stepper.execute(&"c 4 2".parse()?)?;
+        let view = stepper.view();
+        assert!(view.starts_with("Round 2, next turn: Goblin #0 at (3, 1)\n"));
+        assert!(view.contains("   E(197), G(197)\n"));
+        assert!(view.ends_with("Cursor (4, 2): Elf #1 with 197 HP and an attack power of 3\n"));
+
+        stepper.execute(&"a".parse()?)?;
+        assert!(stepper.view().ends_with("Cursor (3, 2): no unit\n"));
+
+        assert!(stepper.execute(&"c 7 0".parse()?).is_err());
+        assert!("x".parse::<Command>().is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use std::{cmp::Reverse, fmt::Debug};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{game::Game, location::Location, unit::Unit, unit::UnitId};
+
+/// Square in range of an enemy, which a unit can reach.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Destination {
+    pub location: Location,
+    /// Number of steps to reach the square.
+    pub distance: u32,
+}
+
+/// Decisions every unit of a race makes during its turn.
+///
+/// The provided methods follow the rules of the puzzle, so strategies only have to override the
+/// decisions they want to make differently.
+pub trait Strategy: Debug + Send + Sync {
+    /// Picks the enemy to attack among the ones in range, which are given in reading-order.
+    ///
+    /// Attacks the enemy with the fewest hit points, ties are broken in reading-order.
+    fn choose_target(&self, _game: &Game, _unit: &Unit, targets: &[&Unit]) -> Option<UnitId> {
+        targets
+            .iter()
+            .min_by_key(|target| (target.hit_points, &target.location))
+            .map(|target| target.id)
+    }
+
+    /// Picks the square to head for, the unit moves a single step along the shortest path to it.
+    ///
+    /// Heads for the closest square, ties are broken in reading-order.
+    fn choose_destination(
+        &self,
+        _game: &Game,
+        _unit: &Unit,
+        destinations: &[Destination],
+    ) -> Option<Location> {
+        destinations
+            .iter()
+            .min_by_key(|destination| (destination.distance, &destination.location))
+            .map(|destination| destination.location.clone())
+    }
+
+    /// Decides whether a unit without an enemy in range stays where it is instead of moving.
+    ///
+    /// Units always move.
+    fn hold_position(&self, _game: &Game, _unit: &Unit) -> bool {
+        false
+    }
+}
+
+/// Rules of the puzzle, used by every race without a strategy of its own.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PuzzleRules;
+
+impl Strategy for PuzzleRules {}
+
+/// Attacks the enemy in range with the most hit points to weaken the strongest units first.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FocusStrongest;
+
+impl Strategy for FocusStrongest {
+    fn choose_target(&self, _game: &Game, _unit: &Unit, targets: &[&Unit]) -> Option<UnitId> {
+        targets
+            .iter()
+            .min_by_key(|target| (Reverse(target.hit_points), &target.location))
+            .map(|target| target.id)
+    }
+}
+
+/// Waits until an enemy comes close, instead of marching towards it.
+#[derive(Debug, Clone, Copy)]
+pub struct Ambush {
+    /// Manhattan distance an enemy has to come close to, before the unit starts moving.
+    pub reach: usize,
+}
+
+impl Default for Ambush {
+    fn default() -> Self {
+        Ambush { reach: 3 }
+    }
+}
+
+impl Strategy for Ambush {
+    fn hold_position(&self, game: &Game, unit: &Unit) -> bool {
+        game.units()
+            .filter(|other| game.legend().is_hostile(&unit.race, &other.race))
+            .all(|enemy| enemy.location.distance(&unit.location) > self.reach)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, sync::Arc};
+
+    use super::*;
+    use crate::unit::Race;
+
+    #[test]
+    fn focus_strongest_enemy() -> crate::Result<()> {
+        let initial = "#####\n\
+                       #.G.#\n\
+                       #GEG#\n\
+                       #####";
+
+        let mut game = Game::from_str(initial)?;
+        game.adjust_unit(&Location::new(3, 2), Some(150), None)?;
+        game.adjust_unit(&Location::new(1, 2), Some(100), None)?;
+
+        let mut focused = game.clone();
+        focused.set_strategy(Race::elf(), Arc::new(FocusStrongest));
+
+        game.next_turn();
+        focused.next_turn();
+
+        // Goblins strike first, the elf then either picks the weakest or strongest goblin.
+        let hit_points = |game: &Game| game.units().map(|unit| unit.hit_points).collect::<Vec<_>>();
+        assert_eq!(vec![200, 97, 191, 150], hit_points(&game));
+        assert_eq!(vec![197, 100, 191, 150], hit_points(&focused));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ambush_holds_position() -> crate::Result<()> {
+        let initial = "#########\n\
+                       #E.....G#\n\
+                       #########";
+
+        let mut game = Game::from_str(initial)?;
+        game.set_strategy(Race::elf(), Arc::new(Ambush { reach: 2 }));
+
+        game.next_turn();
+        game.next_turn();
+
+        let locations = game
+            .units()
+            .map(|unit| unit.location.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Location::new(1, 1), Location::new(5, 1)], locations);
+
+        game.next_turn();
+        game.next_turn();
+        game.next_turn();
+
+        let locations = game
+            .units()
+            .map(|unit| unit.location.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Location::new(2, 1), Location::new(3, 1)], locations);
+
+        Ok(())
+    }
+}
@@ -1,7 +1,5 @@
 use std::fmt::{self, Display, Formatter};
 
-use crate::Result;
-
 #[derive(Debug, Clone)]
 pub enum Tile {
     Wall,
@@ -9,22 +7,17 @@ pub enum Tile {
 }
 
 impl Tile {
-    pub fn from_char(symbol: char) -> Result<Self> {
-        let tile = match symbol {
-            '.' => Tile::Floor,
-            '#' => Tile::Wall,
-            _ => return Err(format!("Unknown map tile: {}", symbol))?,
-        };
-
-        Ok(tile)
+    /// Nothing if the symbol doesn't belong to a tile.
+    pub fn from_char(symbol: char) -> Option<Self> {
+        match symbol {
+            '.' => Some(Tile::Floor),
+            '#' => Some(Tile::Wall),
+            _ => None,
+        }
     }
 
     pub fn is_free(&self) -> bool {
-        if let Tile::Floor = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Tile::Floor)
     }
 }
 
@@ -0,0 +1,237 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::Game,
+    strategy::{Ambush, FocusStrongest, PuzzleRules, Strategy},
+    unit::Race,
+};
+
+/// Strategy taking part in a tournament under a unique name.
+#[derive(Debug, Clone)]
+pub struct Contender {
+    pub name: String,
+    pub strategy: Arc<dyn Strategy>,
+}
+
+/// Pits strategies against each other on several maps.
+///
+/// On every map each race is led by one contender. All lineups are played, except the ones in
+/// which a single contender leads every race.
+#[derive(Debug, Clone)]
+pub struct Tournament {
+    pub maps: Vec<(String, Game)>,
+    pub contenders: Vec<Contender>,
+    /// Battles lasting more full rounds end in a draw, e.g. if every side waits in ambush.
+    pub max_rounds: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Battle {
+    pub map: String,
+    /// Contender leading each race.
+    pub lineup: BTreeMap<Race, String>,
+    /// Nothing if the battle ended in a draw.
+    pub winner: Option<Race>,
+    /// Contenders leading the winning race or one of its allies.
+    pub winners: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Standing {
+    pub contender: String,
+    pub battles: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub win_rate: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TournamentReport {
+    pub battles: Vec<Battle>,
+    /// Contenders ordered by their win rate, starting with the highest.
+    pub standings: Vec<Standing>,
+}
+
+impl Contender {
+    pub fn new(name: &str, strategy: impl Strategy + 'static) -> Self {
+        Contender {
+            name: name.to_owned(),
+            strategy: Arc::new(strategy),
+        }
+    }
+
+    /// Every strategy shipped with the simulator.
+    pub fn builtin() -> Vec<Contender> {
+        vec![
+            Contender::new("puzzle-rules", PuzzleRules),
+            Contender::new("focus-strongest", FocusStrongest),
+            Contender::new("ambush", Ambush::default()),
+        ]
+    }
+}
+
+impl Tournament {
+    /// Fights all battles concurrently.
+    pub fn run(&self) -> TournamentReport {
+        let lineups = self
+            .maps
+            .iter()
+            .flat_map(|(map, game)| {
+                let races = game
+                    .units()
+                    .map(|unit| unit.race.clone())
+                    .collect::<BTreeSet<_>>();
+
+                self.lineups(races.len()).map(move |contenders| {
+                    let lineup = races.iter().cloned().zip(contenders).collect::<Vec<_>>();
+                    (map, game, lineup)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let battles = lineups
+            .into_par_iter()
+            .map(|(map, game, lineup)| self.fight(map, game, &lineup))
+            .collect::<Vec<_>>();
+
+        let mut standings = self
+            .contenders
+            .iter()
+            .map(|contender| Self::standing(&contender.name, &battles))
+            .collect::<Vec<_>>();
+        standings.sort_by(|first, second| second.win_rate.total_cmp(&first.win_rate));
+
+        TournamentReport { battles, standings }
+    }
+
+    /// Every assignment of contenders to the given number of races, except the ones with a single
+    /// contender for every race.
+    fn lineups(&self, num_races: usize) -> impl Iterator<Item = Vec<&Contender>> {
+        let num_contenders = self.contenders.len();
+        let num_lineups = num_contenders.pow(num_races as u32);
+
+        (0..num_lineups)
+            .map(move |mut lineup| {
+                (0..num_races)
+                    .map(|_| {
+                        let contender = &self.contenders[lineup % num_contenders];
+                        lineup /= num_contenders;
+                        contender
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|lineup| {
+                lineup
+                    .iter()
+                    .any(|contender| contender.name != lineup[0].name)
+            })
+    }
+
+    fn fight(&self, map: &str, game: &Game, lineup: &[(Race, &Contender)]) -> Battle {
+        let mut game = game.clone();
+        for (race, contender) in lineup {
+            game.set_strategy(race.clone(), contender.strategy.clone());
+        }
+
+        let winner = game.run_for(self.max_rounds).map(|report| report.winner);
+        let winners = match &winner {
+            Some(winner) => lineup
+                .iter()
+                .filter(|(race, _)| !game.legend().is_hostile(race, winner))
+                .map(|(_, contender)| contender.name.clone())
+                .collect(),
+            None => BTreeSet::new(),
+        };
+
+        Battle {
+            map: map.to_owned(),
+            lineup: lineup
+                .iter()
+                .map(|(race, contender)| (race.clone(), contender.name.clone()))
+                .collect(),
+            winner,
+            winners,
+        }
+    }
+
+    fn standing(contender: &str, battles: &[Battle]) -> Standing {
+        let fought = battles
+            .iter()
+            .filter(|battle| battle.lineup.values().any(|name| name == contender))
+            .collect::<Vec<_>>();
+
+        let battles = fought.len() as u32;
+        let wins = fought
+            .iter()
+            .filter(|battle| battle.winners.contains(contender))
+            .count() as u32;
+        let draws = fought
+            .iter()
+            .filter(|battle| battle.winner.is_none())
+            .count() as u32;
+
+        Standing {
+            contender: contender.to_owned(),
+            battles,
+            wins,
+            draws,
+            win_rate: match battles {
+                0 => 0.0,
+                _ => f64::from(wins) / f64::from(battles),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const COMBAT: &str = "#######\n\
+                          #.G...#\n\
+                          #...EG#\n\
+                          #.#.#G#\n\
+                          #..G#E#\n\
+                          #.....#\n\
+                          #######";
+
+    #[test]
+    fn play_every_lineup() -> crate::Result<()> {
+        let tournament = Tournament {
+            maps: vec![("combat".to_owned(), Game::from_str(COMBAT)?)],
+            contenders: Contender::builtin(),
+            max_rounds: 100,
+        };
+
+        let report = tournament.run();
+
+        // Three contenders for two races, without the three mirror matches.
+        assert_eq!(6, report.battles.len());
+        assert!(report
+            .battles
+            .iter()
+            .all(|battle| battle.lineup.len() == 2 && battle.winners.len() <= 1));
+
+        for standing in &report.standings {
+            assert_eq!(4, standing.battles);
+            assert!(standing.wins + standing.draws <= standing.battles);
+        }
+
+        let win_rates = report
+            .standings
+            .iter()
+            .map(|standing| standing.win_rate)
+            .collect::<Vec<_>>();
+        assert!(win_rates.windows(2).all(|pair| pair[0] >= pair[1]));
+
+        Ok(())
+    }
+}
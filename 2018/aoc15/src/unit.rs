@@ -1,42 +1,57 @@
-use std::fmt::{self, Display, Formatter};
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::Arc,
+};
 
-use crate::{location::Location, Result};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+use crate::location::Location;
+
+/// Stable index of a unit inside its game, assigned in reading-order of the initial map.
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Copy, Clone, Serialize, Deserialize)]
+pub struct UnitId(pub usize);
+
+#[derive(Debug, Clone)]
 pub struct Unit {
+    pub id: UnitId,
+    pub symbol: char,
     pub attack_power: u32,
     pub hit_points: u32,
     pub race: Race,
     pub location: Location,
 }
 
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
-pub enum Race {
-    Elf,
-    Gnome,
+/// Faction a unit fights for, identified by its name.
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Race(Arc<str>);
+
+/// Race, hit points and attack power of the units represented by a single symbol.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnitTemplate {
+    pub race: Race,
+    #[serde(default = "UnitTemplate::default_hit_points")]
+    pub hit_points: u32,
+    #[serde(default = "UnitTemplate::default_attack_power")]
+    pub attack_power: u32,
 }
 
 impl Unit {
-    pub fn from_char(symbol: char) -> Result<Self> {
-        let race = Race::from_char(symbol)?;
-
-        Ok(Unit {
-            race,
-            attack_power: 3,
-            hit_points: 200,
-            location: Location { x: 0, y: 0 },
-        })
-    }
-
-    /// Returns true if the target dies through the attack.
-    pub fn attack(&self, target: &mut Unit) -> bool {
-        if target.hit_points < self.attack_power {
-            target.hit_points = 0;
-        } else {
-            target.hit_points -= self.attack_power;
+    pub fn new(id: UnitId, symbol: char, template: &UnitTemplate, location: Location) -> Self {
+        Unit {
+            id,
+            symbol,
+            race: template.race.clone(),
+            attack_power: template.attack_power,
+            hit_points: template.hit_points,
+            location,
         }
+    }
 
-        !target.alive()
+    /// Returns true if the unit dies through the attack.
+    pub fn suffer_attack(&mut self, attack_power: u32) -> bool {
+        self.hit_points = self.hit_points.saturating_sub(attack_power);
+        !self.alive()
     }
 
     pub fn alive(&self) -> bool {
@@ -45,26 +60,53 @@ impl Unit {
 }
 
 impl Race {
-    fn from_char(symbol: char) -> Result<Self> {
-        let race = match symbol {
-            'E' => Race::Elf,
-            'G' => Race::Gnome,
-            _ => return Err(format!("Unknown race symbol: {}", symbol))?,
-        };
-
-        Ok(race)
+    pub fn new(name: &str) -> Self {
+        Race(Arc::from(name))
+    }
+
+    pub fn elf() -> Self {
+        Race::new("Elf")
+    }
+
+    pub fn goblin() -> Self {
+        Race::new("Goblin")
     }
 
-    fn to_char(self) -> char {
-        match self {
-            Race::Elf => 'E',
-            Race::Gnome => 'G',
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_elf(&self) -> bool {
+        self.name() == "Elf"
+    }
+}
+
+impl UnitTemplate {
+    pub fn new(race: Race) -> Self {
+        UnitTemplate {
+            race,
+            hit_points: Self::default_hit_points(),
+            attack_power: Self::default_attack_power(),
         }
     }
+
+    fn default_hit_points() -> u32 {
+        200
+    }
+
+    fn default_attack_power() -> u32 {
+        3
+    }
 }
 
 impl Display for Unit {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        self.race.to_char().fmt(f)
+        self.symbol.fmt(f)
+    }
+}
+
+impl Display for Race {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.name().fmt(f)
     }
 }
@@ -0,0 +1,162 @@
+use std::fmt::Write;
+
+use crate::{
+    instruction::Instruction,
+    opcode::{Opcode, OperandKind},
+    program::Program,
+    Result,
+};
+
+/// Turns source like `addi r1 2 r1` into a program.
+///
+/// Registers may be written as `r<n>` or as plain number, immediates only as plain number. Lines
+/// may start with the `#ip <register>` directive and everything after a `;` is a comment.
+/// Numeric opcodes are accepted as well, their operands aren't checked.
+pub fn assemble(source: &str) -> Result<Program> {
+    let mut program = Program::default();
+
+    for (index, line) in source.lines().enumerate() {
+        let line = line.split(';').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let parsed = match line.strip_prefix("#ip") {
+            Some(_) if program.ip_register.is_some() => Err("Duplicate directive.".into()),
+            Some(register) => parse_operand(register.trim(), OperandKind::Register)
+                .map(|register| program.ip_register = Some(register)),
+            None => parse_instruction(line).map(|instruction| {
+                program.instructions.push(instruction);
+            }),
+        };
+
+        parsed.map_err(|error| format!("Line {}: {} {}", index + 1, line, error))?;
+    }
+
+    Ok(program)
+}
+
+/// Renders the program with mnemonics and register names, in a form [`assemble`] reads back.
+pub fn disassemble(program: &Program) -> String {
+    let mut source = String::new();
+
+    if let Some(register) = program.ip_register {
+        writeln!(source, "#ip r{}", register).expect("Writing to a string never fails.");
+    }
+
+    for instruction in &program.instructions {
+        writeln!(source, "{}", disassemble_instruction(instruction))
+            .expect("Writing to a string never fails.");
+    }
+
+    source
+}
+
+/// Single instruction with mnemonic and register names, unknown opcodes stay numeric.
+pub fn disassemble_instruction(instruction: &Instruction) -> String {
+    let Instruction {
+        opcode,
+        input_lhs,
+        input_rhs,
+        output,
+    } = *instruction;
+
+    match Opcode::from_u8(opcode) {
+        Some(opcode) => {
+            let (lhs_kind, rhs_kind) = opcode.operands();
+            format!(
+                "{} {} {} {}",
+                opcode,
+                format_operand(input_lhs, lhs_kind),
+                format_operand(input_rhs, rhs_kind),
+                format_operand(output, OperandKind::Register)
+            )
+        }
+        None => format!("{} {} {} {}", opcode, input_lhs, input_rhs, output),
+    }
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction> {
+    let parts = line.split_whitespace().collect::<Vec<_>>();
+    if parts.len() != 4 {
+        Err("Expected an opcode and three operands.")?;
+    }
+
+    let (opcode, (lhs_kind, rhs_kind)) = match parts[0].parse::<Opcode>() {
+        Ok(opcode) => (opcode.into(), opcode.operands()),
+        Err(_) => (
+            parts[0].parse()?,
+            (OperandKind::Ignored, OperandKind::Ignored),
+        ),
+    };
+
+    Ok(Instruction {
+        opcode,
+        input_lhs: parse_operand(parts[1], lhs_kind)?,
+        input_rhs: parse_operand(parts[2], rhs_kind)?,
+        output: parse_operand(parts[3], OperandKind::Register)?,
+    })
+}
+
+fn parse_operand(operand: &str, kind: OperandKind) -> Result<u32> {
+    match operand.strip_prefix('r') {
+        Some(register) if kind == OperandKind::Register => Ok(register.parse()?),
+        Some(_) => Err(format!("Expected an immediate instead of {}.", operand))?,
+        None => Ok(operand.parse()?),
+    }
+}
+
+fn format_operand(value: u32, kind: OperandKind) -> String {
+    match kind {
+        OperandKind::Register => format!("r{}", value),
+        OperandKind::Immediate | OperandKind::Ignored => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NUMERIC: &str = "#ip 3
+                           9 0 0 1
+                           1 1 2 1
+                           11 2 4 0
+                           0 0 3 3
+                           8 2 7 3
+                           13 5 0 2";
+
+    const SOURCE: &str = "#ip r3\n\
+                          seti 0 0 r1\n\
+                          addi r1 2 r1\n\
+                          gtri r2 4 r0\n\
+                          addr r0 r3 r3\n\
+                          setr r2 7 r3\n\
+                          eqir 5 r0 r2\n";
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let program = NUMERIC.parse::<Program>()?;
+        let source = disassemble(&program);
+
+        assert_eq!(SOURCE, source);
+        assert_eq!(program, assemble(&source)?);
+        assert_eq!(program, program.to_string().parse()?);
+        assert_eq!(source, disassemble(&assemble(&source)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_operands() -> Result<()> {
+        let program = assemble("; counts up\naddi 1 2 1 ; plain registers\n16 1 2 3")?;
+        assert_eq!("addi r1 2 r1\n16 1 2 3\n", disassemble(&program));
+
+        assert!(assemble("addi r1 r2 r1").is_err());
+        assert!(assemble("gtir r5 r0 r2").is_err());
+        assert!(assemble("addi r1 2").is_err());
+        assert!(assemble("nope 1 2 3").is_err());
+        assert!(assemble("#ip 1\n#ip 2").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use std::{env, io, process};
+
+use aoc16::{
+    assembly::{assemble, disassemble},
+    cfg::Cfg,
+    debugger::Debugger,
+    operation::{Executor, Halt},
+    program::Program,
+    state::State,
+    Result,
+};
+use aoc_common::Input;
+
+const USAGE: &str = "Usage:
+    device asm <source>
+    device disasm <program>
+    device debug <program> [registers, e.g. \"[1, 0, 0, 0, 0, 0]\"]
+    device profile <program> [registers] [step limit]
+    device decompile <program>
+    device dot <program>";
+
+/// Number of hottest blocks and back-edges the profile lists.
+const PROFILE_TOP: usize = 5;
+
+fn main() -> Result<()> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    match args.as_slice() {
+        ["asm", source] => print!("{}", assemble(Input::from_path(source)?.as_str())?),
+        ["disasm", program] => {
+            let program = Input::from_path(program)?.as_str().parse::<Program>()?;
+            print!("{}", disassemble(&program));
+        }
+        ["debug", program, registers @ ..] => {
+            let program = assemble(Input::from_path(program)?.as_str())?;
+            match registers.first() {
+                Some(registers) if registers.split(',').count() == 4 => {
+                    debug(executor::<4>(program, Some(registers))?)?
+                }
+                registers => debug(executor::<6>(program, registers)?)?,
+            }
+        }
+        ["profile", program, rest @ ..] => {
+            let program = assemble(Input::from_path(program)?.as_str())?;
+            let step_limit = rest.get(1).map(|limit| limit.parse()).transpose()?;
+            match rest.first() {
+                Some(registers) if registers.split(',').count() == 4 => {
+                    profile(executor::<4>(program, Some(registers))?, step_limit)
+                }
+                registers => profile(executor::<6>(program, registers)?, step_limit),
+            }
+        }
+        ["decompile", program] => {
+            let program = assemble(Input::from_path(program)?.as_str())?;
+            print!(
+                "{}",
+                Cfg::new(&program.instructions, program.ip_register).pseudo_code()
+            );
+        }
+        ["dot", program] => {
+            let program = assemble(Input::from_path(program)?.as_str())?;
+            print!(
+                "{}",
+                Cfg::new(&program.instructions, program.ip_register).dot()
+            );
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Executor with `N` registers, six like the later device programs unless four are given.
+fn executor<const N: usize>(
+    program: Program,
+    registers: Option<&&str>,
+) -> Result<Executor<u64, N>> {
+    let state = match registers {
+        Some(registers) => registers.parse::<State<u64, N>>()?,
+        None => State::default(),
+    };
+
+    Ok(Executor::default().with_program(program).with_state(state))
+}
+
+fn debug<const N: usize>(executor: Executor<u64, N>) -> Result<()> {
+    Debugger::new(executor).run(io::stdin().lock(), io::stdout().lock())
+}
+
+fn profile<const N: usize>(executor: Executor<u64, N>, step_limit: Option<u64>) {
+    let mut executor = executor.with_profiling();
+    if let Some(step_limit) = step_limit {
+        executor = executor.with_step_limit(step_limit);
+    }
+
+    let halt = executor.run();
+    if let Some(profile) = executor.profile() {
+        print!("{}", profile.annotate(executor.instructions(), PROFILE_TOP));
+    }
+
+    let reason = match halt {
+        Halt::LeftProgram { .. } => "left the program".to_string(),
+        Halt::StepLimit { .. } => "reached the step limit".to_string(),
+        Halt::Fault { fault, .. } => fault.to_string(),
+    };
+    println!(
+        "\nHalted at {} after {} instructions, {}: {}",
+        executor.ip(),
+        executor.steps(),
+        reason,
+        executor.state()
+    );
+}
@@ -0,0 +1,992 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
+    fmt::Write,
+};
+
+use crate::{
+    assembly::disassemble_instruction,
+    instruction::Instruction,
+    opcode::{Opcode, OperandKind},
+};
+
+/// Control-flow graph of a program, derived from its instructions without running them.
+///
+/// Only instructions writing the register bound to the instruction pointer change the control
+/// flow. Their target is known if the inputs are immediates or the instruction pointer itself,
+/// e.g. `seti 4 0 ip` or `addi ip 2 ip`. Adding the result of the comparison right before to the
+/// instruction pointer, e.g. `addr r2 ip ip`, skips the next instruction or not, unless another
+/// instruction jumps to the addition.
+#[derive(Debug, Clone)]
+pub struct Cfg {
+    instructions: Vec<Instruction>,
+    ip_register: Option<u32>,
+    blocks: Vec<BasicBlock>,
+}
+
+/// Instructions which always execute together, starting with the only one jumped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BasicBlock {
+    pub start: usize,
+    /// First instruction after the block.
+    pub end: usize,
+    pub exit: Exit,
+}
+
+/// Where the execution continues after a block, targets at or beyond the end halt the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    /// The last instruction doesn't write the instruction pointer.
+    Next(usize),
+    Jump(usize),
+    /// Continues at `taken` if the register holds `1`, otherwise at `skip`.
+    Branch {
+        condition: u32,
+        skip: usize,
+        taken: usize,
+    },
+    /// The target depends on values only known at runtime.
+    Dynamic,
+}
+
+/// Blocks which can repeat, identified by the start of their blocks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Loop {
+    /// The only block through which the loop is entered, as far as the program is reducible.
+    pub header: usize,
+    /// Blocks jumping back to the header.
+    pub latches: Vec<usize>,
+    pub body: BTreeSet<usize>,
+}
+
+impl Exit {
+    pub fn successors(&self) -> Vec<usize> {
+        match *self {
+            Exit::Next(target) | Exit::Jump(target) => vec![target],
+            Exit::Branch { skip, taken, .. } => vec![skip, taken],
+            Exit::Dynamic => vec![],
+        }
+    }
+}
+
+impl Cfg {
+    pub fn new(instructions: &[Instruction], ip_register: Option<u32>) -> Self {
+        let len = instructions.len();
+        let mut control = (0..len)
+            .map(|ip| control_flow(instructions, ip_register, ip))
+            .collect::<Vec<_>>();
+
+        // A jump to a branch skips the comparison, the condition may hold anything then.
+        let jump_targets = control
+            .iter()
+            .flatten()
+            .filter_map(|exit| match *exit {
+                Exit::Jump(target) | Exit::Branch { taken: target, .. } => Some(target),
+                Exit::Next(_) | Exit::Dynamic => None,
+            })
+            .collect::<BTreeSet<_>>();
+        for (ip, exit) in control.iter_mut().enumerate() {
+            if matches!(exit, Some(Exit::Branch { .. })) && jump_targets.contains(&ip) {
+                *exit = Some(Exit::Dynamic);
+            }
+        }
+
+        let mut leaders = BTreeSet::new();
+        leaders.insert(0);
+        for (ip, exit) in control.iter().enumerate() {
+            if let Some(exit) = exit {
+                leaders.insert(ip + 1);
+                leaders.extend(exit.successors());
+            }
+        }
+
+        let leaders = leaders
+            .into_iter()
+            .filter(|&ip| ip < len)
+            .chain(Some(len))
+            .collect::<Vec<_>>();
+
+        let blocks = leaders
+            .windows(2)
+            .map(|bounds| BasicBlock {
+                start: bounds[0],
+                end: bounds[1],
+                exit: control[bounds[1] - 1].unwrap_or(Exit::Next(bounds[1])),
+            })
+            .collect();
+
+        Cfg {
+            instructions: instructions.to_vec(),
+            ip_register,
+            blocks,
+        }
+    }
+
+    /// Blocks in the order of their instructions.
+    pub fn blocks(&self) -> &[BasicBlock] {
+        &self.blocks
+    }
+
+    pub fn block_at(&self, start: usize) -> Option<&BasicBlock> {
+        self.index_of(start).map(|index| &self.blocks[index])
+    }
+
+    /// Edges between the start of a block and the instruction the execution continues at.
+    pub fn edges(&self) -> Vec<(usize, usize)> {
+        self.blocks
+            .iter()
+            .flat_map(|block| {
+                block
+                    .exit
+                    .successors()
+                    .into_iter()
+                    .map(move |target| (block.start, target))
+            })
+            .collect()
+    }
+
+    /// Natural loops found by a depth-first search from the first block, ordered by header.
+    pub fn loops(&self) -> Vec<Loop> {
+        let mut back_edges = vec![];
+        let mut visited = vec![false; self.blocks.len()];
+        let mut on_stack = vec![false; self.blocks.len()];
+
+        // blocks only reached through dynamic jumps get searched afterwards
+        for index in 0..self.blocks.len() {
+            if !visited[index] {
+                self.find_back_edges(index, &mut visited, &mut on_stack, &mut back_edges);
+            }
+        }
+
+        let mut loops = BTreeMap::<usize, Loop>::new();
+        for (latch, header) in back_edges {
+            let body = self.loop_body(latch, header);
+            let entry = loops.entry(header).or_insert_with(|| Loop {
+                header: self.blocks[header].start,
+                latches: vec![],
+                body: BTreeSet::new(),
+            });
+
+            entry.latches.push(self.blocks[latch].start);
+            entry
+                .body
+                .extend(body.into_iter().map(|index| self.blocks[index].start));
+        }
+
+        loops
+            .into_values()
+            .map(|mut found| {
+                found.latches.sort_unstable();
+                found
+            })
+            .collect()
+    }
+
+    /// Structured pseudo-code with a variable per register, loops become `while` and skipped
+    /// instructions `if`. Control flow which doesn't fit into that structure uses labels.
+    pub fn pseudo_code(&self) -> String {
+        let mut writer = PseudoCode {
+            cfg: self,
+            loops: self.contiguous_loops(),
+            lines: vec![],
+            block_lines: BTreeMap::new(),
+            labels: BTreeSet::new(),
+        };
+
+        let context = Context {
+            header: None,
+            exit: None,
+        };
+        writer.region(0, self.blocks.len(), self.instructions.len(), context, 0);
+        writer.finish()
+    }
+
+    /// Graph in the Graphviz DOT language with the disassembly of each block.
+    pub fn dot(&self) -> String {
+        let len = self.instructions.len();
+        let back_edges = self
+            .loops()
+            .iter()
+            .flat_map(|found| {
+                found
+                    .latches
+                    .iter()
+                    .map(move |&latch| (latch, found.header))
+            })
+            .collect::<BTreeSet<_>>();
+
+        let mut dot = "digraph cfg {\n    node [shape=box, fontname=\"monospace\"];\n".to_string();
+        let node = |target: usize| {
+            if target >= len {
+                "halt".to_string()
+            } else {
+                format!("b{}", target)
+            }
+        };
+
+        for block in &self.blocks {
+            let label = (block.start..block.end)
+                .map(|ip| {
+                    format!(
+                        "{}: {}\\l",
+                        ip,
+                        disassemble_instruction(&self.instructions[ip])
+                    )
+                })
+                .collect::<String>();
+            writeln!(dot, "    b{} [label=\"{}\"];", block.start, label)
+                .expect("Writing never fails.");
+        }
+
+        if self.edges().iter().any(|&(_, target)| target >= len) || self.blocks.is_empty() {
+            dot += "    halt [shape=doublecircle];\n";
+        }
+        if self.blocks.iter().any(|block| block.exit == Exit::Dynamic) {
+            dot += "    dynamic [shape=diamond, label=\"?\"];\n";
+        }
+
+        for block in &self.blocks {
+            let from = node(block.start);
+            let edge = |target: usize, label: Option<String>| {
+                let mut attributes = vec![];
+                if let Some(label) = label {
+                    attributes.push(format!("label=\"{}\"", label));
+                }
+                if back_edges.contains(&(block.start, target)) {
+                    attributes.push("style=bold".to_string());
+                }
+
+                let attributes = match attributes.is_empty() {
+                    true => String::new(),
+                    false => format!(" [{}]", attributes.join(", ")),
+                };
+                format!("    {} -> {}{};\n", from, node(target), attributes)
+            };
+
+            dot += &match block.exit {
+                Exit::Next(target) | Exit::Jump(target) => edge(target, None),
+                Exit::Branch {
+                    condition,
+                    skip,
+                    taken,
+                } => {
+                    edge(skip, Some(format!("r{} == 0", condition)))
+                        + &edge(taken, Some(format!("r{} != 0", condition)))
+                }
+                Exit::Dynamic => format!("    {} -> dynamic [style=dashed];\n", from),
+            };
+        }
+
+        dot + "}\n"
+    }
+
+    fn index_of(&self, start: usize) -> Option<usize> {
+        self.blocks
+            .binary_search_by_key(&start, |block| block.start)
+            .ok()
+    }
+
+    fn successor_indices(&self, index: usize) -> Vec<usize> {
+        self.blocks[index]
+            .exit
+            .successors()
+            .into_iter()
+            .filter_map(|target| self.index_of(target))
+            .collect()
+    }
+
+    fn find_back_edges(
+        &self,
+        index: usize,
+        visited: &mut [bool],
+        on_stack: &mut [bool],
+        back_edges: &mut Vec<(usize, usize)>,
+    ) {
+        visited[index] = true;
+        on_stack[index] = true;
+
+        for successor in self.successor_indices(index) {
+            if on_stack[successor] {
+                back_edges.push((index, successor));
+            } else if !visited[successor] {
+                self.find_back_edges(successor, visited, on_stack, back_edges);
+            }
+        }
+
+        on_stack[index] = false;
+    }
+
+    /// Header and every block reaching the latch without passing the header.
+    fn loop_body(&self, latch: usize, header: usize) -> BTreeSet<usize> {
+        let mut body = BTreeSet::new();
+        body.insert(header);
+
+        let mut pending = vec![latch];
+        while let Some(index) = pending.pop() {
+            if body.insert(index) {
+                pending.extend(
+                    (0..self.blocks.len()).filter(|&predecessor| {
+                        self.successor_indices(predecessor).contains(&index)
+                    }),
+                );
+            }
+        }
+
+        body
+    }
+
+    /// Loops which occupy all blocks from their header to their last block, as indices of the
+    /// first and last block by the index of the header.
+    fn contiguous_loops(&self) -> BTreeMap<usize, usize> {
+        self.loops()
+            .iter()
+            .filter_map(|found| {
+                let first = self.index_of(found.header)?;
+                let last = self.index_of(*found.body.iter().next_back()?)?;
+                let contiguous =
+                    (first..=last).all(|index| found.body.contains(&self.blocks[index].start));
+
+                contiguous.then_some((first, last))
+            })
+            .collect()
+    }
+
+    /// Starts of the blocks continuing at the given instruction.
+    fn predecessors(&self, start: usize) -> Vec<usize> {
+        self.edges()
+            .into_iter()
+            .filter(|&(_, target)| target == start)
+            .map(|(from, _)| from)
+            .collect()
+    }
+
+    fn operand(&self, value: u32, kind: OperandKind, ip: usize) -> String {
+        match kind {
+            OperandKind::Register if Some(value) == self.ip_register => ip.to_string(),
+            OperandKind::Register => format!("r{}", value),
+            OperandKind::Immediate | OperandKind::Ignored => value.to_string(),
+        }
+    }
+
+    /// Value the instruction at `ip` computes, with the instruction pointer as constant.
+    fn expression(&self, ip: usize) -> String {
+        let instruction = &self.instructions[ip];
+        let opcode = match Opcode::from_u8(instruction.opcode) {
+            Some(opcode) => opcode,
+            None => return format!("unknown({})", instruction),
+        };
+
+        let (lhs_kind, rhs_kind) = opcode.operands();
+        let lhs = self.operand(instruction.input_lhs, lhs_kind, ip);
+        let rhs = self.operand(instruction.input_rhs, rhs_kind, ip);
+
+        match symbol(opcode) {
+            Some(symbol) => format!("{} {} {}", lhs, symbol, rhs),
+            None => lhs,
+        }
+    }
+
+    fn statement(&self, ip: usize) -> String {
+        let instruction = &self.instructions[ip];
+        let output = format!("r{}", instruction.output);
+
+        // all operations with a symbol besides the comparisons are commutative
+        if let Some(opcode) = Opcode::from_u8(instruction.opcode).filter(|&op| !is_comparison(op)) {
+            let (lhs_kind, rhs_kind) = opcode.operands();
+            let is_output = |value: u32, kind: OperandKind| {
+                kind == OperandKind::Register && value == instruction.output
+            };
+
+            let other = if is_output(instruction.input_lhs, lhs_kind) {
+                Some(self.operand(instruction.input_rhs, rhs_kind, ip))
+            } else if is_output(instruction.input_rhs, rhs_kind) {
+                Some(self.operand(instruction.input_lhs, lhs_kind, ip))
+            } else {
+                None
+            };
+
+            if let (Some(other), Some(symbol)) = (other, symbol(opcode)) {
+                return format!("{} {}= {}", output, symbol, other);
+            }
+        }
+
+        format!("{} = {}", output, self.expression(ip))
+    }
+}
+
+/// Loop the pseudo-code currently is in.
+#[derive(Debug, Clone, Copy)]
+struct Context {
+    header: Option<usize>,
+    /// Instruction after the loop.
+    exit: Option<usize>,
+}
+
+struct PseudoCode<'a> {
+    cfg: &'a Cfg,
+    loops: BTreeMap<usize, usize>,
+    lines: Vec<(usize, String)>,
+    /// Line the code of each block starts at.
+    block_lines: BTreeMap<usize, usize>,
+    /// Blocks targeted by a `goto`.
+    labels: BTreeSet<usize>,
+}
+
+impl PseudoCode<'_> {
+    /// Writes the blocks `first..end`, `fallthrough` is where the execution continues after them.
+    fn region(
+        &mut self,
+        first: usize,
+        end: usize,
+        fallthrough: usize,
+        context: Context,
+        indent: usize,
+    ) {
+        let blocks = self.cfg.blocks.clone();
+        let mut index = first;
+
+        while index < end {
+            let block = blocks[index];
+            let next = |index: usize| blocks.get(index).filter(|_| index < end);
+            let natural = next(index + 1).map_or(fallthrough, |block| block.start);
+
+            match self.loops.get(&index).copied() {
+                Some(last) if context.header != Some(block.start) && last < end => {
+                    let exit = blocks
+                        .get(last + 1)
+                        .map_or(self.cfg.instructions.len(), |b| b.start);
+                    let inner = Context {
+                        header: Some(block.start),
+                        exit: Some(exit),
+                    };
+
+                    self.block_lines.insert(block.start, self.lines.len());
+                    self.line(indent, "while true {".to_string());
+                    self.region(index, last + 1, block.start, inner, indent + 1);
+                    self.line(indent, "}".to_string());
+
+                    // continuing after the loop needs a jump, unless the next block follows
+                    let after = next(last + 1).map_or(fallthrough, |block| block.start);
+                    if let Some(jump) = self.jump(exit, Some(after), context) {
+                        self.line(indent, jump);
+                    }
+
+                    index = last + 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            self.block_lines.insert(block.start, self.lines.len());
+            let last_statement = match block.exit {
+                Exit::Next(_) => block.end,
+                _ => block.end - 1,
+            };
+            for ip in block.start..last_statement {
+                let statement = self.cfg.statement(ip);
+                self.line(indent, statement);
+            }
+
+            match block.exit {
+                Exit::Next(target) | Exit::Jump(target) => {
+                    if let Some(jump) = self.jump(target, Some(natural), context) {
+                        self.line(indent, jump);
+                    }
+                }
+                Exit::Dynamic => {
+                    let target = self.cfg.expression(block.end - 1);
+                    self.line(indent, format!("goto *({} + 1)", target));
+                }
+                Exit::Branch {
+                    condition,
+                    skip,
+                    taken,
+                } => {
+                    let skipped = next(index + 1).copied().filter(|skipped| {
+                        skipped.start == skip
+                            && skipped.end == skip + 1
+                            && matches!(skipped.exit, Exit::Next(_) | Exit::Jump(_))
+                            && self.cfg.predecessors(skip).len() == 1
+                            && !self.loops.contains_key(&(index + 1))
+                    });
+
+                    match skipped {
+                        Some(skipped) => {
+                            if let Some((last, target)) =
+                                self.guarded(index, skipped, end, fallthrough)
+                            {
+                                self.block_lines.insert(skipped.start, self.lines.len());
+                                self.line(indent, format!("if r{} != 0 {{", condition));
+                                self.region(index + 2, last, target, context, indent + 1);
+                                self.line(indent, "}".to_string());
+
+                                index = last;
+                                continue;
+                            }
+
+                            let after = next(index + 2).map_or(fallthrough, |block| block.start);
+                            self.branch(condition, skipped, taken, after, context, indent);
+                            index += 1;
+                        }
+                        None => {
+                            let jump = self.jump(taken, None, context).unwrap_or_default();
+                            self.conditional(indent, format!("r{} != 0", condition), jump);
+                        }
+                    }
+                }
+            }
+
+            index += 1;
+        }
+    }
+
+    /// Blocks the skipped instruction jumps over if the branch isn't taken, as index of the
+    /// first block after them and the target of the jump. Only blocks entered through the branch
+    /// qualify.
+    fn guarded(
+        &self,
+        index: usize,
+        skipped: BasicBlock,
+        end: usize,
+        fallthrough: usize,
+    ) -> Option<(usize, usize)> {
+        let blocks = &self.cfg.blocks;
+        let target = match skipped.exit {
+            Exit::Jump(target) => target,
+            _ => return None,
+        };
+
+        let last = match (index + 2..end).find(|&last| blocks[last].start == target) {
+            Some(last) => last,
+            None if target == fallthrough => end,
+            None => return None,
+        };
+
+        let guarded = (index + 2..last)
+            .map(|guarded| blocks[guarded].start)
+            .collect::<BTreeSet<_>>();
+        let entered_inside = guarded.iter().all(|&start| {
+            self.cfg
+                .predecessors(start)
+                .iter()
+                .all(|from| *from == blocks[index].start || guarded.contains(from))
+        });
+
+        (!guarded.is_empty() && entered_inside).then_some((last, target))
+    }
+
+    /// Writes a branch whose skipped instruction gets absorbed into an `if`.
+    fn branch(
+        &mut self,
+        condition: u32,
+        skipped: BasicBlock,
+        taken: usize,
+        after: usize,
+        context: Context,
+        indent: usize,
+    ) {
+        self.block_lines.insert(skipped.start, self.lines.len());
+        let taken_jump = self.jump(taken, Some(after), context);
+
+        match skipped.exit {
+            Exit::Jump(target) => match (taken_jump, self.jump(target, Some(after), context)) {
+                (None, None) => {}
+                (None, Some(skip_jump)) => {
+                    self.conditional(indent, format!("r{} == 0", condition), skip_jump)
+                }
+                (Some(taken_jump), None) => {
+                    self.conditional(indent, format!("r{} != 0", condition), taken_jump)
+                }
+                (Some(taken_jump), Some(skip_jump)) => {
+                    self.conditional(indent, format!("r{} != 0", condition), taken_jump);
+                    self.line(indent, skip_jump);
+                }
+            },
+            _ => {
+                let statement = self.cfg.statement(skipped.start);
+                self.conditional(indent, format!("r{} == 0", condition), statement);
+                if let Some(taken_jump) = taken_jump {
+                    self.line(indent, taken_jump);
+                }
+            }
+        }
+    }
+
+    /// Statement to continue at the target, nothing if the execution gets there anyway.
+    fn jump(&mut self, target: usize, natural: Option<usize>, context: Context) -> Option<String> {
+        if Some(target) == natural {
+            None
+        } else if Some(target) == context.header {
+            Some("continue".to_string())
+        } else if Some(target) == context.exit {
+            Some("break".to_string())
+        } else if target >= self.cfg.instructions.len() {
+            Some("halt".to_string())
+        } else {
+            self.labels.insert(target);
+            Some(format!("goto L{}", target))
+        }
+    }
+
+    fn conditional(&mut self, indent: usize, condition: String, statement: String) {
+        self.line(indent, format!("if {} {{", condition));
+        self.line(indent + 1, statement);
+        self.line(indent, "}".to_string());
+    }
+
+    fn line(&mut self, indent: usize, line: String) {
+        self.lines.push((indent, line));
+    }
+
+    fn finish(mut self) -> String {
+        let mut header = String::new();
+        if let Some(register) = self.cfg.ip_register {
+            writeln!(header, "// r{} holds the instruction pointer", register)
+                .expect("Writing never fails.");
+        }
+
+        for &target in self.labels.iter().rev() {
+            if let Some(&line) = self.block_lines.get(&target) {
+                let indent = self.lines.get(line).map_or(0, |(indent, _)| *indent);
+                self.lines.insert(line, (indent, format!("L{}:", target)));
+            }
+        }
+
+        self.lines.iter().fold(header, |mut code, (indent, line)| {
+            writeln!(code, "{:1$}{2}", "", indent * 4, line).expect("Writing never fails.");
+            code
+        })
+    }
+}
+
+/// Where the instruction at `ip` continues, nothing if it doesn't write the instruction pointer.
+fn control_flow(instructions: &[Instruction], ip_register: Option<u32>, ip: usize) -> Option<Exit> {
+    let register = ip_register?;
+    let instruction = &instructions[ip];
+    if instruction.output != register {
+        return None;
+    }
+
+    let opcode = match Opcode::from_u8(instruction.opcode) {
+        Some(opcode) => opcode,
+        None => return Some(Exit::Dynamic),
+    };
+
+    let (lhs_kind, rhs_kind) = opcode.operands();
+    let constant = |value: u32, kind: OperandKind| match kind {
+        OperandKind::Register => (value == register).then_some(ip as u64),
+        OperandKind::Immediate => Some(u64::from(value)),
+        OperandKind::Ignored => Some(0),
+    };
+
+    let lhs = constant(instruction.input_lhs, lhs_kind);
+    let rhs = constant(instruction.input_rhs, rhs_kind);
+    if let (Some(lhs), Some(rhs)) = (lhs, rhs) {
+        return Some(match fold(opcode, lhs, rhs) {
+            Some(target) => Exit::Jump(usize::try_from(target + 1).unwrap_or(usize::MAX)),
+            None => Exit::Dynamic,
+        });
+    }
+
+    let condition = match (opcode, lhs, rhs) {
+        (Opcode::Addr, Some(_), None) => instruction.input_rhs,
+        (Opcode::Addr, None, Some(_)) => instruction.input_lhs,
+        _ => return Some(Exit::Dynamic),
+    };
+
+    let compared = ip
+        .checked_sub(1)
+        .and_then(|previous| instructions.get(previous))
+        .filter(|previous| previous.output == condition)
+        .and_then(|previous| Opcode::from_u8(previous.opcode))
+        .is_some_and(is_comparison);
+
+    match compared {
+        true => Some(Exit::Branch {
+            condition,
+            skip: ip + 1,
+            taken: ip + 2,
+        }),
+        false => Some(Exit::Dynamic),
+    }
+}
+
+/// Result of the operation on constant inputs, nothing if it doesn't fit into the narrowest
+/// register. Executors wrap, saturate or fault depending on their word type and arithmetic then.
+fn fold(opcode: Opcode, lhs: u64, rhs: u64) -> Option<u64> {
+    let result = match opcode {
+        Opcode::Addr | Opcode::Addi => lhs.checked_add(rhs)?,
+        Opcode::Mulr | Opcode::Muli => lhs.checked_mul(rhs)?,
+        Opcode::Banr | Opcode::Bani => lhs & rhs,
+        Opcode::Borr | Opcode::Bori => lhs | rhs,
+        Opcode::Setr | Opcode::Seti => lhs,
+        Opcode::Gtir | Opcode::Gtri | Opcode::Gtrr => u64::from(lhs > rhs),
+        Opcode::Eqir | Opcode::Eqri | Opcode::Eqrr => u64::from(lhs == rhs),
+    };
+
+    (result <= u64::from(u32::MAX)).then_some(result)
+}
+
+fn is_comparison(opcode: Opcode) -> bool {
+    matches!(
+        opcode,
+        Opcode::Gtir | Opcode::Gtri | Opcode::Gtrr | Opcode::Eqir | Opcode::Eqri | Opcode::Eqrr
+    )
+}
+
+fn symbol(opcode: Opcode) -> Option<&'static str> {
+    match opcode {
+        Opcode::Addr | Opcode::Addi => Some("+"),
+        Opcode::Mulr | Opcode::Muli => Some("*"),
+        Opcode::Banr | Opcode::Bani => Some("&"),
+        Opcode::Borr | Opcode::Bori => Some("|"),
+        Opcode::Setr | Opcode::Seti => None,
+        Opcode::Gtir | Opcode::Gtri | Opcode::Gtrr => Some(">"),
+        Opcode::Eqir | Opcode::Eqri | Opcode::Eqrr => Some("=="),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{program::Program, Result};
+
+    /// Adds 2 to register 1 until register 2 counted to 5.
+    const LOOP: &str = "#ip 3
+                        seti 0 0 1
+                        addi 1 2 1
+                        addi 2 1 2
+                        gtri 2 4 0
+                        addr 0 3 3
+                        seti 0 0 3";
+
+    /// Counts in register 0 how often register 1 isn't 3, while it counts up to 6.
+    const NESTED: &str = "#ip 5
+                          seti 0 0 1
+                          addi 1 1 1
+                          eqri 1 3 2
+                          addr 2 5 5
+                          addi 0 1 0
+                          gtri 1 5 2
+                          addr 2 5 5
+                          seti 0 0 5
+                          seti 9 0 3";
+
+    /// Sums the divisors of register 4, like the device program of day 19.
+    const DIVISORS: &str = "#ip 3
+                            addi 3 0 3
+                            seti 1 2 5
+                            seti 1 3 2
+                            mulr 5 2 1
+                            eqrr 1 4 1
+                            addr 1 3 3
+                            addi 3 1 3
+                            addr 5 0 0
+                            addi 2 1 2
+                            gtrr 2 4 1
+                            addr 3 1 3
+                            seti 2 3 3
+                            addi 5 1 5
+                            gtrr 5 4 1
+                            addr 1 3 3
+                            seti 1 2 3
+                            mulr 3 3 3";
+
+    fn cfg(program: &str) -> Result<Cfg> {
+        let program = program.parse::<Program>()?;
+        Ok(Cfg::new(&program.instructions, program.ip_register))
+    }
+
+    #[test]
+    fn find_blocks_and_loops() -> Result<()> {
+        let cfg = cfg(NESTED)?;
+
+        let blocks = cfg
+            .blocks()
+            .iter()
+            .map(|block| (block.start, block.end, block.exit))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (0, 1, Exit::Next(1)),
+                (
+                    1,
+                    4,
+                    Exit::Branch {
+                        condition: 2,
+                        skip: 4,
+                        taken: 5
+                    }
+                ),
+                (4, 5, Exit::Next(5)),
+                (
+                    5,
+                    7,
+                    Exit::Branch {
+                        condition: 2,
+                        skip: 7,
+                        taken: 8
+                    }
+                ),
+                (7, 8, Exit::Jump(1)),
+                (8, 9, Exit::Next(9)),
+            ],
+            blocks
+        );
+
+        assert_eq!(
+            vec![Loop {
+                header: 1,
+                latches: vec![7],
+                body: vec![1, 4, 5, 7].into_iter().collect(),
+            }],
+            cfg.loops()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn classify_jumps() -> Result<()> {
+        let program = "#ip 3\n\
+                       gtri 1 4 0\n\
+                       addr 0 3 3\n\
+                       addi 3 4294967295 3\n\
+                       seti 0 0 3";
+
+        // The jump to the addition skips the comparison, so the target isn't known. Neither is the
+        // one of the overflowing addition, which depends on the word type and arithmetic.
+        let exits = cfg(program)?
+            .blocks()
+            .iter()
+            .map(|block| (block.start, block.exit))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (0, Exit::Next(1)),
+                (1, Exit::Dynamic),
+                (2, Exit::Dynamic),
+                (3, Exit::Jump(1))
+            ],
+            exits
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn decompile_loops_and_conditions() -> Result<()> {
+        assert_eq!(
+            "// r3 holds the instruction pointer\n\
+             r1 = 0\n\
+             while true {\n    \
+                 r1 += 2\n    \
+                 r2 += 1\n    \
+                 r0 = r2 > 4\n    \
+                 if r0 != 0 {\n        \
+                     break\n    \
+                 }\n\
+             }\n",
+            cfg(LOOP)?.pseudo_code()
+        );
+
+        assert_eq!(
+            "// r5 holds the instruction pointer\n\
+             r1 = 0\n\
+             while true {\n    \
+                 r1 += 1\n    \
+                 r2 = r1 == 3\n    \
+                 if r2 == 0 {\n        \
+                     r0 += 1\n    \
+                 }\n    \
+                 r2 = r1 > 5\n    \
+                 if r2 != 0 {\n        \
+                     break\n    \
+                 }\n\
+             }\n\
+             r3 = 9\n",
+            cfg(NESTED)?.pseudo_code()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn decompile_nested_loops() -> Result<()> {
+        let cfg = cfg(DIVISORS)?;
+
+        let headers = cfg
+            .loops()
+            .iter()
+            .map(|found| found.header)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![2, 3], headers);
+
+        assert_eq!(
+            "// r3 holds the instruction pointer\n\
+             r5 = 1\n\
+             while true {\n    \
+                 r2 = 1\n    \
+                 while true {\n        \
+                     r1 = r5 * r2\n        \
+                     r1 = r1 == r4\n        \
+                     if r1 != 0 {\n            \
+                         r0 += r5\n        \
+                     }\n        \
+                     r2 += 1\n        \
+                     r1 = r2 > r4\n        \
+                     if r1 != 0 {\n            \
+                         break\n        \
+                     }\n    \
+                 }\n    \
+                 r5 += 1\n    \
+                 r1 = r5 > r4\n    \
+                 if r1 != 0 {\n        \
+                     break\n    \
+                 }\n\
+             }\n\
+             halt\n",
+            cfg.pseudo_code()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn decompile_unstructured_jumps() -> Result<()> {
+        let program = "#ip 4\n\
+                       seti 2 0 4\n\
+                       seti 7 0 0\n\
+                       setr 1 0 4\n\
+                       mulr 4 4 4";
+
+        assert_eq!(
+            "// r4 holds the instruction pointer\n\
+             goto L3\n\
+             r0 = 7\n\
+             goto *(r1 + 1)\n\
+             L3:\n\
+             halt\n",
+            cfg(program)?.pseudo_code()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn render_dot() -> Result<()> {
+        let dot = cfg(LOOP)?.dot();
+
+        assert!(dot.starts_with("digraph cfg {\n"));
+        assert!(dot.contains(
+            "    b1 [label=\"1: addi r1 2 r1\\l2: addi r2 1 r2\\l3: gtri r2 4 r0\\l\
+             4: addr r0 r3 r3\\l\"];\n"
+        ));
+        assert!(dot.contains("    b1 -> b5 [label=\"r0 == 0\"];\n"));
+        assert!(dot.contains("    b1 -> halt [label=\"r0 != 0\"];\n"));
+        assert!(dot.contains("    b5 -> b1 [style=bold];\n"));
+        assert!(dot.ends_with("}\n"));
+
+        Ok(())
+    }
+}
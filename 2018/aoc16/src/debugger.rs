@@ -0,0 +1,385 @@
+use std::{
+    collections::VecDeque,
+    convert::TryFrom,
+    error::Error,
+    io::{BufRead, Write},
+    str::FromStr,
+};
+
+use crate::{
+    assembly::disassemble_instruction,
+    operation::{Executor, Halt, Snapshot},
+    state::{State, Word},
+    Result,
+};
+
+const HELP: &str = "[s]tep [n]  [c]ontinue  [b]reak <index>  [w]atch <register> [value]  \
+                    [d]elete <breakpoint>  [l]ist  [p]rint  [r]everse [n]  [q]uit";
+
+/// Oldest steps get forgotten beyond this, so long runs don't exhaust the memory.
+const HISTORY_LIMIT: usize = 1_000_000;
+
+/// Single line of input to the debugger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command<WordT = u32> {
+    /// Executes the given number of instructions, a single one for an empty line.
+    Step(u64),
+    /// Executes instructions until a breakpoint hits or the program halts.
+    Continue,
+    Break(Breakpoint<WordT>),
+    /// Removes the breakpoint with the given number.
+    Delete(usize),
+    List,
+    Print,
+    /// Reverts the given number of instructions, none if fewer are remembered.
+    Reverse(u64),
+    Quit,
+}
+
+/// Condition to pause the execution on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint<WordT = u32> {
+    /// Before the instruction at the index executes.
+    At(usize),
+    /// After an instruction changed the register.
+    Changed(u32),
+    /// After an instruction set the register to the value, while it held another one before.
+    Equals(u32, WordT),
+}
+
+/// Why the execution paused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pause {
+    /// All requested steps are executed.
+    Stepped,
+    /// Breakpoint with the given number hit.
+    Breakpoint(usize),
+    Halted(Halt),
+}
+
+/// Executes a program under control of breakpoints and remembers every step to revert it.
+#[derive(Debug, Clone)]
+pub struct Debugger<WordT: Word = u32, const N: usize = 4> {
+    executor: Executor<WordT, N>,
+    breakpoints: Vec<Breakpoint<WordT>>,
+    /// Executor before each step, the latest last.
+    history: VecDeque<Snapshot<WordT, N>>,
+}
+
+impl<WordT: Word, const N: usize> Debugger<WordT, N>
+where
+    WordT::Err: Error + 'static,
+{
+    pub fn new(executor: Executor<WordT, N>) -> Self {
+        Debugger {
+            executor,
+            breakpoints: vec![],
+            history: VecDeque::new(),
+        }
+    }
+
+    pub fn executor(&self) -> &Executor<WordT, N> {
+        &self.executor
+    }
+
+    /// Reads commands line by line and prints the registers after each one until the input ends.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> Result<()> {
+        write!(output, "{}\n{}", HELP, self.view())?;
+        output.flush()?;
+
+        for line in input.lines() {
+            let response = match line?.parse() {
+                Ok(Command::Quit) => return Ok(()),
+                Ok(command) => match self.execute(&command) {
+                    Ok(response) => response,
+                    Err(error) => error.to_string(),
+                },
+                Err(error) => error.to_string(),
+            };
+
+            if !response.is_empty() {
+                writeln!(output, "{}", response)?;
+            }
+            write!(output, "{}", self.view())?;
+            output.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Executes the command and describes its outcome, if there is anything to tell.
+    pub fn execute(&mut self, command: &Command<WordT>) -> Result<String> {
+        let response = match command {
+            Command::Step(steps) => {
+                let pause = self.resume(Some(*steps));
+                self.describe(pause)
+            }
+            Command::Continue => {
+                let pause = self.resume(None);
+                self.describe(pause)
+            }
+            Command::Break(breakpoint) => {
+                self.breakpoints.push(*breakpoint);
+                format!(
+                    "Breakpoint {}: {:?}",
+                    self.breakpoints.len() - 1,
+                    breakpoint
+                )
+            }
+            Command::Delete(index) => {
+                if *index >= self.breakpoints.len() {
+                    Err(format!("There is no breakpoint {}.", index))?;
+                }
+
+                let breakpoint = self.breakpoints.remove(*index);
+                format!("Deleted {:?}", breakpoint)
+            }
+            Command::List => self
+                .breakpoints
+                .iter()
+                .enumerate()
+                .map(|(index, breakpoint)| format!("Breakpoint {}: {:?}", index, breakpoint))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Command::Print => format!("{}", self.executor.state()),
+            Command::Reverse(steps) => {
+                let available = self.history.len();
+                let steps = match usize::try_from(*steps) {
+                    Ok(steps) if steps <= available => steps,
+                    _ => Err(format!("Only {} steps can be reversed.", available))?,
+                };
+
+                if let Some(&snapshot) = self.history.get(available - steps) {
+                    self.executor.restore(snapshot);
+                }
+                self.history.truncate(available - steps);
+
+                String::new()
+            }
+            Command::Quit => String::new(),
+        };
+
+        Ok(response)
+    }
+
+    /// Executes at most the given number of instructions, but stops at breakpoints and halts.
+    /// Breakpoints at the instruction pointer the execution starts from don't stop it.
+    pub fn resume(&mut self, max_steps: Option<u64>) -> Pause {
+        let mut steps = 0;
+
+        loop {
+            if max_steps.is_some_and(|max_steps| steps >= max_steps) {
+                return Pause::Stepped;
+            }
+
+            if steps > 0 {
+                if let Some(index) = self.breakpoint_at(self.executor.ip()) {
+                    return Pause::Breakpoint(index);
+                }
+            }
+
+            let before = self.executor.snapshot();
+            if let Some(halt) = self.executor.step() {
+                return Pause::Halted(halt);
+            }
+
+            if self.history.len() == HISTORY_LIMIT {
+                self.history.pop_front();
+            }
+            self.history.push_back(before);
+            steps += 1;
+
+            if let Some(index) = self.watch_hit(&before.state) {
+                return Pause::Breakpoint(index);
+            }
+        }
+    }
+
+    /// Instruction pointer, number of executed instructions, registers and next instruction.
+    pub fn view(&self) -> String {
+        let ip = self.executor.ip();
+        let next = match self.executor.instructions().get(ip) {
+            Some(instruction) => disassemble_instruction(instruction),
+            None => "outside of the program".to_string(),
+        };
+
+        format!(
+            "#{} ip {:>3}  {}  {}\n> ",
+            self.executor.steps(),
+            ip,
+            self.executor.state(),
+            next
+        )
+    }
+
+    fn describe(&self, pause: Pause) -> String {
+        match pause {
+            Pause::Stepped => String::new(),
+            Pause::Breakpoint(index) => {
+                format!("Breakpoint {} hit: {:?}", index, self.breakpoints[index])
+            }
+            Pause::Halted(Halt::LeftProgram { ip }) => {
+                format!("Halted, the instruction pointer {} left the program.", ip)
+            }
+            Pause::Halted(Halt::StepLimit { ip }) => {
+                format!("Halted at {}, the step limit is reached.", ip)
+            }
+            Pause::Halted(Halt::Fault { ip, fault }) => {
+                format!("Halted at {}: {}", ip, fault)
+            }
+        }
+    }
+
+    fn breakpoint_at(&self, ip: usize) -> Option<usize> {
+        self.breakpoints
+            .iter()
+            .position(|breakpoint| *breakpoint == Breakpoint::At(ip))
+    }
+
+    fn watch_hit(&self, before: &State<WordT, N>) -> Option<usize> {
+        let after = self.executor.state();
+        let changed = |register: u32| before.get(register).ok() != after.get(register).ok();
+
+        self.breakpoints
+            .iter()
+            .position(|breakpoint| match *breakpoint {
+                Breakpoint::At(_) => false,
+                Breakpoint::Changed(register) => changed(register),
+                Breakpoint::Equals(register, value) => {
+                    changed(register) && after.get(register) == Ok(value)
+                }
+            })
+    }
+}
+
+impl<WordT: Word> FromStr for Command<WordT>
+where
+    WordT::Err: Error + 'static,
+{
+    type Err = Box<dyn Error>;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let register = |word: &str| word.trim_start_matches('r').parse::<u32>();
+
+        let command = match words.as_slice() {
+            [] | ["s"] => Command::Step(1),
+            ["s", steps] => Command::Step(steps.parse()?),
+            ["c"] => Command::Continue,
+            ["b", index] => Command::Break(Breakpoint::At(index.parse()?)),
+            ["w", reg] => Command::Break(Breakpoint::Changed(register(reg)?)),
+            ["w", reg, value] => Command::Break(Breakpoint::Equals(register(reg)?, value.parse()?)),
+            ["d", index] => Command::Delete(index.parse()?),
+            ["l"] => Command::List,
+            ["p"] => Command::Print,
+            ["r"] => Command::Reverse(1),
+            ["r", steps] => Command::Reverse(steps.parse()?),
+            ["q"] => Command::Quit,
+            _ => Err(format!("Unknown command: {}", line))?,
+        };
+
+        Ok(command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Adds 2 to register 1 until register 2 counted to 5.
+    const LOOP: &str = "#ip 3
+                        seti 0 0 1
+                        addi 1 2 1
+                        addi 2 1 2
+                        gtri 2 4 0
+                        addr 0 3 3
+                        seti 0 0 3";
+
+    fn debugger() -> Result<Debugger> {
+        Ok(Debugger::new(
+            Executor::default().with_program(LOOP.parse()?),
+        ))
+    }
+
+    fn execute(debugger: &mut Debugger, line: &str) -> Result<String> {
+        debugger.execute(&line.parse()?)
+    }
+
+    #[test]
+    fn break_and_reverse() -> Result<()> {
+        let mut debugger = debugger()?;
+
+        execute(&mut debugger, "b 3")?;
+        assert_eq!("Breakpoint 0 hit: At(3)", execute(&mut debugger, "c")?);
+        assert_eq!(3, debugger.executor().ip());
+        assert_eq!(&State([0, 2, 1, 2]), debugger.executor().state());
+
+        // The breakpoint at the current instruction doesn't hold the execution.
+        execute(&mut debugger, "c")?;
+        assert_eq!(3, debugger.executor().ip());
+        assert_eq!(&State([0, 4, 2, 2]), debugger.executor().state());
+
+        execute(&mut debugger, "r 5")?;
+        assert_eq!(3, debugger.executor().ip());
+        assert_eq!(&State([0, 2, 1, 2]), debugger.executor().state());
+        assert_eq!(3, debugger.executor().steps());
+
+        execute(&mut debugger, "d 0")?;
+        assert_eq!(
+            "Halted, the instruction pointer 6 left the program.",
+            execute(&mut debugger, "c")?
+        );
+        assert_eq!(&State([1, 10, 5, 5]), debugger.executor().state());
+
+        // Reversing more steps than remembered changes nothing.
+        assert!(execute(&mut debugger, "r 26").is_err());
+        assert_eq!(&State([1, 10, 5, 5]), debugger.executor().state());
+
+        execute(&mut debugger, "r 25")?;
+        assert_eq!(&State::default(), debugger.executor().state());
+        assert!(execute(&mut debugger, "r").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn watch_registers() -> Result<()> {
+        let mut debugger = debugger()?;
+
+        execute(&mut debugger, "w r2 3")?;
+        execute(&mut debugger, "w 0")?;
+        assert_eq!(
+            "Breakpoint 0 hit: Equals(2, 3)",
+            execute(&mut debugger, "c")?
+        );
+        assert_eq!(&State([0, 6, 3, 2]), debugger.executor().state());
+
+        assert_eq!("Breakpoint 1 hit: Changed(0)", execute(&mut debugger, "c")?);
+        assert_eq!(&State([1, 10, 5, 3]), debugger.executor().state());
+
+        assert_eq!("", execute(&mut debugger, "s 1")?);
+        assert_eq!(6, debugger.executor().ip());
+        assert_eq!("[1, 10, 5, 5]", execute(&mut debugger, "p")?);
+
+        assert!("x".parse::<Command>().is_err());
+        assert!("w r1 -1".parse::<Command>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_session() -> Result<()> {
+        let mut debugger = debugger()?;
+        let mut output = vec![];
+
+        debugger.run("b 4\nc\np\nq\ns\n".as_bytes(), &mut output)?;
+
+        let output = String::from_utf8(output)?;
+        assert!(
+            output.contains("Breakpoint 0 hit: At(4)\n#4 ip   4  [0, 2, 1, 3]  addr r0 r3 r3\n")
+        );
+        assert!(output.ends_with("[0, 2, 1, 3]\n#4 ip   4  [0, 2, 1, 3]  addr r0 r3 r3\n> "));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    fmt, iter,
+    str::FromStr,
+};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    instruction::Instruction,
+    opcode::Opcode,
+    operation::{Executor, Halt},
+    state::State,
+    Result,
+};
+
+const NUM_OPCODES: u8 = Opcode::ALL.len() as u8;
+
+pub fn find_overlapping_examples(examples: &[Example]) -> u32 {
+    examples
+        .iter()
+        .filter(|example| example.matching_opcodes().len() >= 3)
+        .count() as u32
+}
+
+/// Maps the opcodes of the examples to the opcodes of the executor.
+///
+/// Each opcode starts with every operation as candidate. The examples rule out the operations
+/// which don't produce their result and the rest gets solved by propagation: an opcode with a
+/// single candidate claims that operation, an operation with a single opcode left belongs to it.
+pub fn find_real_opcodes(
+    examples: &[Example],
+) -> std::result::Result<BTreeMap<u8, u8>, MappingError> {
+    let all_opcodes = (0..NUM_OPCODES).collect::<BTreeSet<u8>>();
+    let mut candidates = vec![all_opcodes; NUM_OPCODES as usize];
+
+    for example in examples {
+        let opcode = example.instruction.opcode;
+        let opcode_candidates = candidates
+            .get_mut(opcode as usize)
+            .ok_or(MappingError::UnknownOpcode(opcode))?;
+
+        let matching = example.matching_opcodes();
+        opcode_candidates.retain(|candidate| matching.contains(candidate));
+    }
+
+    let mut mapping = BTreeMap::new();
+    while mapping.len() < candidates.len() {
+        if let Some((opcode, real_opcode)) = next_assignment(&candidates, &mapping)? {
+            mapping.insert(opcode, real_opcode);
+            candidates.iter_mut().enumerate().for_each(|(other, list)| {
+                if other != opcode as usize {
+                    list.remove(&real_opcode);
+                }
+            });
+            candidates[opcode as usize] = iter::once(real_opcode).collect();
+            continue;
+        }
+
+        let (opcode, list) = candidates
+            .iter()
+            .enumerate()
+            .find(|(opcode, _)| !mapping.contains_key(&(*opcode as u8)))
+            .expect("Unassigned opcodes are left.");
+
+        return Err(MappingError::Ambiguous {
+            opcode: opcode as u8,
+            candidates: list.iter().copied().collect(),
+        });
+    }
+
+    Ok(mapping)
+}
+
+/// Next opcode which can only be a single operation, or the opcode of an operation which no
+/// other opcode can be.
+fn next_assignment(
+    candidates: &[BTreeSet<u8>],
+    mapping: &BTreeMap<u8, u8>,
+) -> std::result::Result<Option<(u8, u8)>, MappingError> {
+    let unassigned = || {
+        candidates
+            .iter()
+            .enumerate()
+            .map(|(opcode, list)| (opcode as u8, list))
+            .filter(|(opcode, _)| !mapping.contains_key(opcode))
+    };
+
+    for (opcode, list) in unassigned() {
+        match list.len() {
+            0 => return Err(MappingError::Contradictory { opcode }),
+            1 => return Ok(list.iter().next().map(|&real_opcode| (opcode, real_opcode))),
+            _ => {}
+        }
+    }
+
+    for real_opcode in (0..NUM_OPCODES).filter(|real| !mapping.values().any(|r| r == real)) {
+        let mut opcodes = unassigned().filter(|(_, list)| list.contains(&real_opcode));
+        match (opcodes.next(), opcodes.next()) {
+            (None, _) => return Err(MappingError::Unmatched { real_opcode }),
+            (Some((opcode, _)), None) => return Ok(Some((opcode, real_opcode))),
+            (Some(_), Some(_)) => {}
+        }
+    }
+
+    Ok(None)
+}
+
+/// Reasons why the examples don't lead to a unique opcode mapping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MappingError {
+    /// No operation produces the results of all examples with this opcode.
+    Contradictory {
+        opcode: u8,
+    },
+    /// No opcode is left for this operation, while every opcode needs a distinct one.
+    Unmatched {
+        real_opcode: u8,
+    },
+    /// The examples leave more than one operation for this opcode.
+    Ambiguous {
+        opcode: u8,
+        candidates: Vec<u8>,
+    },
+    UnknownOpcode(u8),
+}
+
+#[derive(Debug)]
+pub struct Example {
+    after: State,
+    before: State,
+    instruction: Instruction,
+}
+
+impl Example {
+    /// Opcodes of the executor which turn the state before into the state after the example.
+    /// Operations failing on the operands, e.g. reading a register which doesn't exist, never
+    /// match.
+    pub fn matching_opcodes(&self) -> BTreeSet<u8> {
+        (0..NUM_OPCODES)
+            .filter(|&opcode| {
+                let instruction = Instruction {
+                    opcode,
+                    ..self.instruction.clone()
+                };
+
+                let mut executor = Executor::default()
+                    .with_state(self.before)
+                    .with_instruction(instruction);
+                let halt = executor.run();
+
+                !matches!(halt, Halt::Fault { .. }) && executor.state() == &self.after
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Example {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self> {
+        lazy_static! {
+            static ref EXAMPLE_RE: Regex = Regex::new(
+                r"Before: (?P<state_before>.*)\s(?P<instruction>.*)\sAfter: (?P<state_after>.*)",
+            )
+            .expect("Predefined example regex failed to compile.");
+        };
+
+        let captures = EXAMPLE_RE
+            .captures(input)
+            .ok_or_else(|| format!("Couldn't parse input entry line: {}", input))?;
+
+        let before = captures["state_before"].parse()?;
+        let after = captures["state_after"].parse()?;
+        let instruction = captures["instruction"].parse()?;
+
+        Ok(Self {
+            after,
+            before,
+            instruction,
+        })
+    }
+}
+
+impl fmt::Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MappingError::Contradictory { opcode } => write!(
+                f,
+                "No operation matches all examples with opcode {}.",
+                opcode
+            ),
+            MappingError::Unmatched { real_opcode } => write!(
+                f,
+                "No opcode is left for {}.",
+                Opcode::ALL[*real_opcode as usize]
+            ),
+            MappingError::Ambiguous { opcode, candidates } => {
+                let names = candidates
+                    .iter()
+                    .map(|&candidate| Opcode::ALL[candidate as usize].mnemonic())
+                    .collect::<Vec<_>>();
+                write!(f, "Opcode {} could be any of {}.", opcode, names.join(", "))
+            }
+            MappingError::UnknownOpcode(opcode) => {
+                write!(f, "Unknown opcode discovered: {}", opcode)
+            }
+        }
+    }
+}
+
+impl Error for MappingError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "Before: [3, 2, 1, 1]\n\
+                          9 2 1 2\n\
+                          After:  [3, 2, 2, 1]";
+
+    #[test]
+    fn match_examples() -> Result<()> {
+        let example = SAMPLE.parse::<Example>()?;
+
+        assert_eq!(BTreeSet::from([1, 2, 9]), example.matching_opcodes());
+        assert_eq!(1, find_overlapping_examples(&[example]));
+
+        // Register 7 doesn't exist, so only `eqir` can leave the registers unchanged.
+        let out_of_range =
+            "Before: [0, 0, 0, 0]\n0 7 0 0\nAfter:  [0, 0, 0, 0]".parse::<Example>()?;
+        assert_eq!(BTreeSet::from([13]), out_of_range.matching_opcodes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn reject_unclear_mappings() -> Result<()> {
+        assert_eq!(
+            Err(MappingError::Ambiguous {
+                opcode: 0,
+                candidates: (0..16).collect()
+            }),
+            find_real_opcodes(&[])
+        );
+
+        // Only the equality checks set the register to 1, but the sample needs another operation.
+        let contradiction = "Before: [0, 0, 0, 0]\n9 0 0 0\nAfter:  [1, 0, 0, 0]".parse()?;
+        assert_eq!(
+            Err(MappingError::Contradictory { opcode: 9 }),
+            find_real_opcodes(&[SAMPLE.parse()?, contradiction])
+        );
+
+        // Every opcode could be several operations, but none of them `addr`.
+        let without_addr = (0..16)
+            .map(|opcode| {
+                format!(
+                    "Before: [1, 0, 0, 0]\n{} 0 0 0\nAfter:  [1, 0, 0, 0]",
+                    opcode
+                )
+                .parse()
+            })
+            .collect::<Result<Vec<Example>>>()?;
+        assert_eq!(
+            Err(MappingError::Unmatched { real_opcode: 0 }),
+            find_real_opcodes(&without_addr)
+        );
+
+        let unknown = "Before: [0, 0, 0, 0]\n16 0 0 0\nAfter:  [0, 0, 0, 0]".parse()?;
+        assert_eq!(
+            Err(MappingError::UnknownOpcode(16)),
+            find_real_opcodes(&[unknown])
+        );
+
+        Ok(())
+    }
+}
@@ -1,28 +1,35 @@
-use std::{error::Error, str::FromStr};
+use std::{error::Error, fmt, str::FromStr};
 
-use crate::Result;
+use crate::{opcode::Opcode, Result};
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Instruction {
     pub opcode: u8,
-    pub input_lhs: u8,
-    pub input_rhs: u8,
-    pub output: u8,
+    pub input_lhs: u32,
+    pub input_rhs: u32,
+    pub output: u32,
 }
 
+/// Accepts the opcode as number or as mnemonic of the executor's operation, e.g. `seti`.
 impl FromStr for Instruction {
     type Err = Box<dyn Error>;
 
     fn from_str(input: &str) -> Result<Self> {
-        let parsed: Vec<u8> = input.split_whitespace().flat_map(u8::from_str).collect();
-        if parsed.len() != 4 {
+        let parts = input.split_whitespace().collect::<Vec<_>>();
+        if parts.len() != 4 {
             return Err(format!("Malformed instruction discovered: {}", input))?;
         }
 
-        let opcode = parsed[0];
-        let input_lhs = parsed[1];
-        let input_rhs = parsed[2];
-        let output = parsed[3];
+        let opcode = match parts[0].parse::<Opcode>() {
+            Ok(opcode) => opcode.into(),
+            Err(_) => parts[0]
+                .parse()
+                .map_err(|_| format!("Unknown opcode discovered: {}", input))?,
+        };
+
+        let input_lhs = parts[1].parse()?;
+        let input_rhs = parts[2].parse()?;
+        let output = parts[3].parse()?;
 
         Ok(Self {
             opcode,
@@ -32,3 +39,14 @@ impl FromStr for Instruction {
         })
     }
 }
+
+/// Formats the instruction with its numeric opcode, like the puzzle input.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.opcode, self.input_lhs, self.input_rhs, self.output
+        )
+    }
+}
@@ -1,7 +1,72 @@
+pub mod assembly;
+pub mod cfg;
+pub mod debugger;
+pub mod example;
 pub mod instruction;
+pub mod opcode;
 pub mod operation;
+pub mod profile;
+pub mod program;
 pub mod state;
 
-use std::error::Error;
+use std::str::FromStr;
 
-pub type Result<ContentT> = std::result::Result<ContentT, Box<dyn Error>>;
+pub use aoc_common::Result;
+use aoc_common::{Answer, Input, Solution};
+
+use crate::{
+    example::{find_overlapping_examples, find_real_opcodes, Example},
+    instruction::Instruction,
+    operation::{Executor, Halt},
+    program::apply_opcode_mapping,
+};
+
+/// Splits the input into the recorded examples and the test program, which are
+/// separated by multiple blank lines.
+pub fn parse_input(input: &str) -> Result<(Vec<Example>, Vec<Instruction>)> {
+    let (examples, program) = input
+        .split_once("\n\n\n")
+        .ok_or("Couldn't find the test program after the examples.")?;
+
+    let examples = examples
+        .trim()
+        .split("\n\n")
+        .map(Example::from_str)
+        .collect::<Result<_>>()?;
+
+    let program = program
+        .trim()
+        .lines()
+        .map(Instruction::from_str)
+        .collect::<Result<_>>()?;
+
+    Ok((examples, program))
+}
+
+pub struct Puzzle {
+    examples: Vec<Example>,
+    program: Vec<Instruction>,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self> {
+        let (examples, program) = parse_input(input.as_str())?;
+        Ok(Self { examples, program })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(find_overlapping_examples(&self.examples).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mapping = find_real_opcodes(&self.examples)?;
+        let program = apply_opcode_mapping(self.program.clone(), &mapping)?;
+
+        let mut executor: Executor = Executor::default().with_instructions(program);
+        match executor.run() {
+            Halt::LeftProgram { .. } => Ok(executor.state()[0].into()),
+            Halt::StepLimit { ip } => Err(format!("Step limit reached at {}.", ip))?,
+            Halt::Fault { ip, fault } => Err(format!("Instruction {} failed: {}", ip, fault))?,
+        }
+    }
+}
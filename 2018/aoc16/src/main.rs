@@ -0,0 +1,5 @@
+use aoc16::{Puzzle, Result};
+
+fn main() -> Result<()> {
+    aoc_common::run::<Puzzle>()
+}
@@ -0,0 +1,128 @@
+use std::{convert::TryFrom, error::Error, fmt, str::FromStr};
+
+use crate::Result;
+
+/// Operations of the device, numbered like the opcodes of the executor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Opcode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+/// How an instruction reads one of its inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperandKind {
+    Register,
+    Immediate,
+    /// The operation doesn't read the input.
+    Ignored,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 16] = [
+        Opcode::Addr,
+        Opcode::Addi,
+        Opcode::Mulr,
+        Opcode::Muli,
+        Opcode::Banr,
+        Opcode::Bani,
+        Opcode::Borr,
+        Opcode::Bori,
+        Opcode::Setr,
+        Opcode::Seti,
+        Opcode::Gtir,
+        Opcode::Gtri,
+        Opcode::Gtrr,
+        Opcode::Eqir,
+        Opcode::Eqri,
+        Opcode::Eqrr,
+    ];
+
+    pub fn from_u8(opcode: u8) -> Option<Self> {
+        Opcode::ALL.get(opcode as usize).copied()
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Addr => "addr",
+            Opcode::Addi => "addi",
+            Opcode::Mulr => "mulr",
+            Opcode::Muli => "muli",
+            Opcode::Banr => "banr",
+            Opcode::Bani => "bani",
+            Opcode::Borr => "borr",
+            Opcode::Bori => "bori",
+            Opcode::Setr => "setr",
+            Opcode::Seti => "seti",
+            Opcode::Gtir => "gtir",
+            Opcode::Gtri => "gtri",
+            Opcode::Gtrr => "gtrr",
+            Opcode::Eqir => "eqir",
+            Opcode::Eqri => "eqri",
+            Opcode::Eqrr => "eqrr",
+        }
+    }
+
+    /// Kinds of the inputs A and B, the output C is always a register.
+    pub fn operands(self) -> (OperandKind, OperandKind) {
+        use OperandKind::*;
+
+        match self {
+            Opcode::Addr | Opcode::Mulr | Opcode::Banr | Opcode::Borr | Opcode::Gtrr => {
+                (Register, Register)
+            }
+            Opcode::Eqrr => (Register, Register),
+            Opcode::Addi | Opcode::Muli | Opcode::Bani | Opcode::Bori => (Register, Immediate),
+            Opcode::Gtri | Opcode::Eqri => (Register, Immediate),
+            Opcode::Gtir | Opcode::Eqir => (Immediate, Register),
+            Opcode::Setr => (Register, Ignored),
+            Opcode::Seti => (Immediate, Ignored),
+        }
+    }
+}
+
+impl From<Opcode> for u8 {
+    fn from(opcode: Opcode) -> Self {
+        opcode as u8
+    }
+}
+
+impl TryFrom<u8> for Opcode {
+    type Error = Box<dyn Error>;
+
+    fn try_from(opcode: u8) -> Result<Self> {
+        Ok(Opcode::from_u8(opcode)
+            .ok_or_else(|| format!("Unknown opcode discovered: {}", opcode))?)
+    }
+}
+
+impl FromStr for Opcode {
+    type Err = Box<dyn Error>;
+
+    fn from_str(mnemonic: &str) -> Result<Self> {
+        Ok(*Opcode::ALL
+            .iter()
+            .find(|opcode| opcode.mnemonic() == mnemonic)
+            .ok_or_else(|| format!("Unknown mnemonic discovered: {}", mnemonic))?)
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())
+    }
+}
@@ -1,34 +1,85 @@
 #![allow(dead_code)]
-use std::collections::VecDeque;
+use std::{error::Error, fmt};
+
+use crate::{
+    instruction::Instruction,
+    opcode::Opcode,
+    profile::Profile,
+    program::Program,
+    state::{InvalidRegister, State, Word},
+};
+
+/// Runs a program instruction by instruction.
+///
+/// Without a register bound to the instruction pointer, instructions run one after the other.
+/// With one, the register holds the current instruction pointer while an instruction executes,
+/// so that writing to it jumps. Either way the program halts once the instruction pointer leaves
+/// it.
+#[derive(Debug, Clone, Default)]
+pub struct Executor<WordT: Word = u32, const N: usize = 4> {
+    state: State<WordT, N>,
+    instructions: Vec<Instruction>,
+    ip: usize,
+    ip_register: Option<u32>,
+    steps: u64,
+    step_limit: Option<u64>,
+    arithmetic: Arithmetic,
+    profile: Option<Profile>,
+}
 
-use crate::{instruction::Instruction, state::State};
+/// How additions and multiplications treat results which don't fit into a register.
+///
+/// The mode decides alone, the result of a program doesn't depend on the build profile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Arithmetic {
+    /// Keeps the lower bits of the result, like the device does.
+    #[default]
+    Wrapping,
+    /// Halts the executor with an overflow fault.
+    Checked,
+    /// Clamps the result to the largest value of the register.
+    Saturating,
+}
 
-#[derive(Debug, Clone)]
-pub struct Executor {
-    state: State,
-    instructions: VecDeque<Instruction>,
+/// Reason why the executor stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Halt {
+    /// The instruction pointer points outside of the program.
+    LeftProgram { ip: usize },
+    /// The number of executed instructions reached the step limit.
+    StepLimit { ip: usize },
+    /// The instruction at the instruction pointer couldn't be executed.
+    Fault { ip: usize, fault: Fault },
 }
 
-impl Default for Executor {
-    fn default() -> Self {
-        Self {
-            state: Default::default(),
-            instructions: Default::default(),
-        }
-    }
+/// Everything about an executor which changes while it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot<WordT = u32, const N: usize = 4> {
+    pub state: State<WordT, N>,
+    pub ip: usize,
+    pub steps: u64,
 }
 
-impl Executor {
-    pub fn with_state(self, state: State) -> Self {
-        let mut executor = Self::default();
-        executor.state = state;
+/// Reason why a single instruction can't be executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    InvalidRegister(InvalidRegister),
+    UnknownOpcode(u8),
+    /// The result of an addition or multiplication doesn't fit into a register, only raised by
+    /// [`Arithmetic::Checked`].
+    Overflow,
+    /// The instruction pointer doesn't fit into the register bound to it.
+    IpOverflow,
+}
 
-        executor
+impl<WordT: Word, const N: usize> Executor<WordT, N> {
+    pub fn with_state(self, state: State<WordT, N>) -> Self {
+        Self { state, ..self }
     }
 
     pub fn with_instruction(mut self, instruction: Instruction) -> Self {
-        self.instructions.push_back(instruction);
-        self
+        self.instructions.push(instruction);
+        self.with_profile_resized()
     }
 
     pub fn with_instructions<Instructions>(mut self, instructions: Instructions) -> Self
@@ -36,56 +87,249 @@ impl Executor {
         Instructions: IntoIterator<Item = Instruction>,
     {
         self.instructions.extend(instructions);
-        self
+        self.with_profile_resized()
     }
 
-    pub fn run(&mut self) -> &State {
-        while let Some(instruction) = self.instructions.pop_front() {
-            self.exec(instruction);
+    /// Loads the instructions of the program and binds the register of its `#ip` directive.
+    pub fn with_program(self, program: Program) -> Self {
+        Self {
+            ip_register: program.ip_register,
+            ..self.with_instructions(program.instructions)
         }
+    }
 
+    pub fn with_ip_register(self, register: u32) -> Self {
+        Self {
+            ip_register: Some(register),
+            ..self
+        }
+    }
+
+    pub fn with_arithmetic(self, arithmetic: Arithmetic) -> Self {
+        Self { arithmetic, ..self }
+    }
+
+    /// Stops the execution after the given number of instructions.
+    pub fn with_step_limit(self, step_limit: u64) -> Self {
+        Self {
+            step_limit: Some(step_limit),
+            ..self
+        }
+    }
+
+    /// Counts the executions of every instruction and the jumps between them, including the
+    /// instructions added afterwards.
+    pub fn with_profiling(self) -> Self {
+        Self {
+            profile: Some(Profile::new(self.instructions.len())),
+            ..self
+        }
+    }
+
+    /// Nothing unless profiling is enabled.
+    pub fn profile(&self) -> Option<&Profile> {
+        self.profile.as_ref()
+    }
+
+    pub fn state(&self) -> &State<WordT, N> {
         &self.state
     }
 
-    pub fn exec(&mut self, instruction: Instruction) {
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn ip_register(&self) -> Option<u32> {
+        self.ip_register
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn snapshot(&self) -> Snapshot<WordT, N> {
+        Snapshot {
+            state: self.state,
+            ip: self.ip,
+            steps: self.steps,
+        }
+    }
+
+    /// Continues from an earlier snapshot of the same program.
+    pub fn restore(&mut self, snapshot: Snapshot<WordT, N>) {
+        self.state = snapshot.state;
+        self.ip = snapshot.ip;
+        self.steps = snapshot.steps;
+    }
+
+    /// Number of instructions executed so far.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Executes instructions until the program halts.
+    pub fn run(&mut self) -> Halt {
+        loop {
+            if let Some(halt) = self.step() {
+                return halt;
+            }
+        }
+    }
+
+    /// Executes the instruction the instruction pointer points to, nothing if the program halts
+    /// before it.
+    pub fn step(&mut self) -> Option<Halt> {
+        let ip = self.ip;
+        let instruction = match self.instructions.get(ip) {
+            Some(instruction) => instruction.clone(),
+            None => return Some(Halt::LeftProgram { ip }),
+        };
+
+        if self.step_limit.is_some_and(|limit| self.steps >= limit) {
+            return Some(Halt::StepLimit { ip });
+        }
+
+        let executed = match self.ip_register {
+            Some(register) => self.exec_bound(instruction, register),
+            None => self.exec(instruction).map(|()| ip + 1),
+        };
+
+        match executed {
+            Ok(next_ip) => self.ip = next_ip,
+            Err(fault) => return Some(Halt::Fault { ip, fault }),
+        }
+
+        if let Some(profile) = &mut self.profile {
+            profile.record(ip, self.ip);
+        }
+
+        self.steps += 1;
+        None
+    }
+
+    pub fn exec(&mut self, instruction: Instruction) -> Result<(), Fault> {
         let lhs = instruction.input_lhs;
         let rhs = instruction.input_rhs;
         let target = instruction.output;
 
-        let op = match instruction.opcode {
-            0 => Addr::exec,
-            1 => Addi::exec,
-            2 => Mulr::exec,
-            3 => Muli::exec,
-            4 => Banr::exec,
-            5 => Bani::exec,
-            6 => Borr::exec,
-            7 => Bori::exec,
-            8 => Setr::exec,
-            9 => Seti::exec,
-            10 => Gtir::exec,
-            11 => Gtri::exec,
-            12 => Gtrr::exec,
-            13 => Eqir::exec,
-            14 => Eqri::exec,
-            15 => Eqrr::exec,
-            _ => panic!("Unknown opcode discovered: {:?}", instruction),
+        let opcode =
+            Opcode::from_u8(instruction.opcode).ok_or(Fault::UnknownOpcode(instruction.opcode))?;
+
+        let op: OperationFn<WordT, N> = match opcode {
+            Opcode::Addr => Addr::exec,
+            Opcode::Addi => Addi::exec,
+            Opcode::Mulr => Mulr::exec,
+            Opcode::Muli => Muli::exec,
+            Opcode::Banr => Banr::exec,
+            Opcode::Bani => Bani::exec,
+            Opcode::Borr => Borr::exec,
+            Opcode::Bori => Bori::exec,
+            Opcode::Setr => Setr::exec,
+            Opcode::Seti => Seti::exec,
+            Opcode::Gtir => Gtir::exec,
+            Opcode::Gtri => Gtri::exec,
+            Opcode::Gtrr => Gtrr::exec,
+            Opcode::Eqir => Eqir::exec,
+            Opcode::Eqri => Eqri::exec,
+            Opcode::Eqrr => Eqrr::exec,
         };
 
-        op(lhs, rhs, target, &mut self.state)
+        op(lhs, rhs, target, self.arithmetic, &mut self.state)
+    }
+
+    fn with_profile_resized(mut self) -> Self {
+        if let Some(profile) = &mut self.profile {
+            profile.resize(self.instructions.len());
+        }
+        self
+    }
+
+    /// Executes the instruction with the instruction pointer in the given register and returns
+    /// the next instruction pointer. Register values which don't fit an instruction pointer lie
+    /// outside of every program, so they saturate. An instruction pointer which doesn't fit the
+    /// register faults with [`Fault::IpOverflow`].
+    fn exec_bound(&mut self, instruction: Instruction, register: u32) -> Result<usize, Fault> {
+        let ip = WordT::from_usize(self.ip).ok_or(Fault::IpOverflow)?;
+        self.state.set(register, ip)?;
+        self.exec(instruction)?;
+
+        let ip = self.state.get(register)?;
+        Ok(ip
+            .to_usize()
+            .and_then(|ip| ip.checked_add(1))
+            .unwrap_or(usize::MAX))
+    }
+}
+
+impl From<InvalidRegister> for Fault {
+    fn from(error: InvalidRegister) -> Self {
+        Fault::InvalidRegister(error)
+    }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::InvalidRegister(error) => write!(f, "{}", error),
+            Fault::UnknownOpcode(opcode) => write!(f, "Unknown opcode discovered: {}", opcode),
+            Fault::Overflow => write!(f, "Arithmetic overflow."),
+            Fault::IpOverflow => write!(f, "The instruction pointer doesn't fit a register."),
+        }
+    }
+}
+
+impl Error for Fault {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Fault::InvalidRegister(error) => Some(error),
+            Fault::UnknownOpcode(_) | Fault::Overflow | Fault::IpOverflow => None,
+        }
+    }
+}
+
+type OperationFn<WordT, const N: usize> =
+    fn(u32, u32, u32, Arithmetic, &mut State<WordT, N>) -> Result<(), Fault>;
+
+impl Arithmetic {
+    pub fn add<WordT: Word>(self, lhs: WordT, rhs: WordT) -> Result<WordT, Fault> {
+        match self {
+            Arithmetic::Wrapping => Ok(lhs.wrapping_add(rhs)),
+            Arithmetic::Checked => lhs.checked_add(rhs).ok_or(Fault::Overflow),
+            Arithmetic::Saturating => Ok(lhs.saturating_add(rhs)),
+        }
+    }
+
+    pub fn mul<WordT: Word>(self, lhs: WordT, rhs: WordT) -> Result<WordT, Fault> {
+        match self {
+            Arithmetic::Wrapping => Ok(lhs.wrapping_mul(rhs)),
+            Arithmetic::Checked => lhs.checked_mul(rhs).ok_or(Fault::Overflow),
+            Arithmetic::Saturating => Ok(lhs.saturating_mul(rhs)),
+        }
     }
 }
 
 trait Operation: Send + Sync {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State);
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault>;
 }
 
 #[derive(Debug)]
 struct Addr;
 
 impl Operation for Addr {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = state[a] + state[b];
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, arithmetic.add(state.get(a)?, state.get(b)?)?)?)
     }
 }
 
@@ -93,8 +337,14 @@ impl Operation for Addr {
 struct Addi;
 
 impl Operation for Addi {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = state[a] + u32::from(b);
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, arithmetic.add(state.get(a)?, WordT::from_u32(b))?)?)
     }
 }
 
@@ -102,8 +352,14 @@ impl Operation for Addi {
 struct Mulr;
 
 impl Operation for Mulr {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = state[a] * state[b]
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, arithmetic.mul(state.get(a)?, state.get(b)?)?)?)
     }
 }
 
@@ -111,8 +367,14 @@ impl Operation for Mulr {
 struct Muli;
 
 impl Operation for Muli {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = state[a] * u32::from(b);
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, arithmetic.mul(state.get(a)?, WordT::from_u32(b))?)?)
     }
 }
 
@@ -120,8 +382,14 @@ impl Operation for Muli {
 struct Banr;
 
 impl Operation for Banr {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = state[a] & state[b];
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, state.get(a)? & state.get(b)?)?)
     }
 }
 
@@ -129,8 +397,14 @@ impl Operation for Banr {
 struct Bani;
 
 impl Operation for Bani {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = state[a] & u32::from(b);
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, state.get(a)? & WordT::from_u32(b))?)
     }
 }
 
@@ -138,8 +412,14 @@ impl Operation for Bani {
 struct Borr;
 
 impl Operation for Borr {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = state[a] | state[b];
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, state.get(a)? | state.get(b)?)?)
     }
 }
 
@@ -147,8 +427,14 @@ impl Operation for Borr {
 struct Bori;
 
 impl Operation for Bori {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = state[a] | u32::from(b);
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, state.get(a)? | WordT::from_u32(b))?)
     }
 }
 
@@ -156,8 +442,14 @@ impl Operation for Bori {
 struct Setr;
 
 impl Operation for Setr {
-    fn exec(a: u8, _b: u8, c: u8, state: &mut State) {
-        state[c] = state[a];
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        _b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, state.get(a)?)?)
     }
 }
 
@@ -165,8 +457,14 @@ impl Operation for Setr {
 struct Seti;
 
 impl Operation for Seti {
-    fn exec(a: u8, _b: u8, c: u8, state: &mut State) {
-        state[c] = u32::from(a);
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        _b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, WordT::from_u32(a))?)
     }
 }
 
@@ -174,8 +472,14 @@ impl Operation for Seti {
 struct Gtir;
 
 impl Operation for Gtir {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = (u32::from(a) > state[b]) as u32;
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, WordT::from_bool(WordT::from_u32(a) > state.get(b)?))?)
     }
 }
 
@@ -183,8 +487,14 @@ impl Operation for Gtir {
 struct Gtri;
 
 impl Operation for Gtri {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = (state[a] > u32::from(b)) as u32;
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, WordT::from_bool(state.get(a)? > WordT::from_u32(b)))?)
     }
 }
 
@@ -192,8 +502,14 @@ impl Operation for Gtri {
 struct Gtrr;
 
 impl Operation for Gtrr {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = (state[a] > state[b]) as u32;
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, WordT::from_bool(state.get(a)? > state.get(b)?))?)
     }
 }
 
@@ -201,8 +517,14 @@ impl Operation for Gtrr {
 struct Eqir;
 
 impl Operation for Eqir {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = (u32::from(a) == state[b]) as u32;
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, WordT::from_bool(WordT::from_u32(a) == state.get(b)?))?)
     }
 }
 
@@ -210,8 +532,14 @@ impl Operation for Eqir {
 struct Eqri;
 
 impl Operation for Eqri {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = (state[a] == u32::from(b)) as u32;
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, WordT::from_bool(state.get(a)? == WordT::from_u32(b)))?)
     }
 }
 
@@ -219,7 +547,168 @@ impl Operation for Eqri {
 struct Eqrr;
 
 impl Operation for Eqrr {
-    fn exec(a: u8, b: u8, c: u8, state: &mut State) {
-        state[c] = (state[a] == state[b]) as u32;
+    fn exec<WordT: Word, const N: usize>(
+        a: u32,
+        b: u32,
+        c: u32,
+        _arithmetic: Arithmetic,
+        state: &mut State<WordT, N>,
+    ) -> Result<(), Fault> {
+        Ok(state.set(c, WordT::from_bool(state.get(a)? == state.get(b)?))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Adds 2 to register 1 until register 2 counted to 5.
+    const LOOP: &str = "#ip 3
+                        seti 0 0 1
+                        addi 1 2 1
+                        addi 2 1 2
+                        gtri 2 4 0
+                        addr 0 3 3
+                        seti 0 0 3";
+
+    #[test]
+    fn jump_through_ip_register() -> crate::Result<()> {
+        let mut executor = Executor::<u32>::default().with_program(LOOP.parse()?);
+
+        assert_eq!(Halt::LeftProgram { ip: 6 }, executor.run());
+        assert_eq!(&State([1, 10, 5, 5]), executor.state());
+        assert_eq!(25, executor.steps());
+
+        Ok(())
+    }
+
+    #[test]
+    fn stop_at_step_limit() -> crate::Result<()> {
+        let mut executor = Executor::<u32>::default()
+            .with_program(LOOP.parse()?)
+            .with_step_limit(10);
+
+        assert_eq!(Halt::StepLimit { ip: 5 }, executor.run());
+        assert_eq!(&State([0, 4, 2, 4]), executor.state());
+        assert_eq!(10, executor.steps());
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_linear_programs() -> crate::Result<()> {
+        let mut executor =
+            Executor::<u32>::default().with_program("seti 7 0 0\naddi 0 3 1".parse()?);
+
+        assert_eq!(Halt::LeftProgram { ip: 2 }, executor.run());
+        assert_eq!(&State([7, 10, 0, 0]), executor.state());
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_wide_states() -> crate::Result<()> {
+        let mut executor = Executor::<u64, 6>::default()
+            .with_state("[0, 0, 0, 0, 0, 4294967295]".parse()?)
+            .with_program("#ip 4\naddi 5 1 5\nmuli 5 2 5".parse()?);
+
+        assert_eq!(Halt::LeftProgram { ip: 2 }, executor.run());
+        assert_eq!("[0, 0, 0, 0, 1, 8589934592]", executor.state().to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fault_on_invalid_registers() -> crate::Result<()> {
+        let mut executor =
+            Executor::<u32>::default().with_program("seti 1 0 0\naddr 0 4 1".parse()?);
+
+        let fault = Fault::InvalidRegister(InvalidRegister {
+            register: 4,
+            num_registers: 4,
+        });
+        assert_eq!(Halt::Fault { ip: 1, fault }, executor.run());
+        assert_eq!(1, executor.steps());
+
+        let mut executor = Executor::<u32>::default()
+            .with_program("seti 1 0 0".parse()?)
+            .with_ip_register(6);
+
+        assert!(matches!(executor.run(), Halt::Fault { ip: 0, .. }));
+
+        Ok(())
+    }
+
+    fn run_with(program: &str, arithmetic: Arithmetic) -> crate::Result<(Halt, State)> {
+        let mut executor = Executor::default()
+            .with_program(program.parse()?)
+            .with_arithmetic(arithmetic);
+
+        Ok((executor.run(), *executor.state()))
+    }
+
+    #[test]
+    fn handle_overflows_by_arithmetic() -> crate::Result<()> {
+        let overflows = [
+            "seti 4294967295 0 0\naddi 0 1 1",
+            "seti 4294967295 0 0\nseti 1 0 2\naddr 0 2 1",
+            "seti 2147483648 0 0\nmuli 0 2 1",
+            "seti 65536 0 0\nmulr 0 0 1",
+        ];
+
+        for program in overflows.iter() {
+            let (halt, state) = run_with(program, Arithmetic::Wrapping)?;
+            assert!(matches!(halt, Halt::LeftProgram { .. }), "{}", program);
+            assert_eq!(0, state[1], "{}", program);
+
+            let (halt, state) = run_with(program, Arithmetic::Saturating)?;
+            assert!(matches!(halt, Halt::LeftProgram { .. }), "{}", program);
+            assert_eq!(u32::MAX, state[1], "{}", program);
+
+            let (halt, state) = run_with(program, Arithmetic::Checked)?;
+            assert!(
+                matches!(
+                    halt,
+                    Halt::Fault {
+                        fault: Fault::Overflow,
+                        ..
+                    }
+                ),
+                "{}",
+                program
+            );
+            assert_eq!(0, state[1], "{}", program);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn stay_below_the_boundaries() -> crate::Result<()> {
+        let fits = [
+            "seti 4294967294 0 0\naddi 0 1 1",
+            "seti 2147483647 0 0\nmuli 0 2 0\naddi 0 1 1",
+            "seti 65535 0 0\nmulr 0 0 0\naddi 0 131070 1",
+        ];
+
+        for arithmetic in [
+            Arithmetic::Wrapping,
+            Arithmetic::Checked,
+            Arithmetic::Saturating,
+        ] {
+            for program in fits.iter() {
+                let (halt, state) = run_with(program, arithmetic)?;
+                assert!(matches!(halt, Halt::LeftProgram { .. }), "{}", program);
+                assert_eq!(u32::MAX, state[1], "{} {:?}", program, arithmetic);
+            }
+        }
+
+        let mut executor = Executor::<u64>::default()
+            .with_program("seti 4294967295 0 0\naddi 0 1 1".parse()?)
+            .with_arithmetic(Arithmetic::Checked);
+        assert_eq!(Halt::LeftProgram { ip: 2 }, executor.run());
+        assert_eq!(1 << 32, executor.state()[1]);
+
+        Ok(())
     }
 }
@@ -0,0 +1,252 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+};
+
+use crate::{assembly::disassemble_instruction, instruction::Instruction};
+
+/// Column of the annotated disassembly in which the jumps of an instruction get listed.
+const JUMP_COLUMN: usize = 40;
+
+/// Execution counts of a program run, collected by [`crate::operation::Executor::with_profiling`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    /// Executions of each instruction.
+    hits: Vec<u64>,
+    /// Number of times the execution continued at another instruction than the next one.
+    jumps: BTreeMap<(usize, usize), u64>,
+}
+
+/// Instructions which always execute together, as far as the run showed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub start: usize,
+    /// First instruction after the block.
+    pub end: usize,
+    /// Number of times the execution entered the block.
+    pub entries: u64,
+    /// Number of instructions executed inside the block.
+    pub executed: u64,
+}
+
+/// Jump to the same or an earlier instruction, which closes a loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackEdge {
+    pub from: usize,
+    pub to: usize,
+    pub count: u64,
+}
+
+impl Profile {
+    pub fn new(num_instructions: usize) -> Self {
+        Profile {
+            hits: vec![0; num_instructions],
+            jumps: BTreeMap::new(),
+        }
+    }
+
+    /// Adapts to a changed number of instructions, keeping the counts of the remaining ones.
+    pub fn resize(&mut self, num_instructions: usize) {
+        self.hits.resize(num_instructions, 0);
+    }
+
+    /// Records the execution of the instruction at `ip`, which continues at `next_ip`.
+    pub fn record(&mut self, ip: usize, next_ip: usize) {
+        if let Some(hits) = self.hits.get_mut(ip) {
+            *hits += 1;
+        }
+
+        if next_ip != ip + 1 {
+            *self.jumps.entry((ip, next_ip)).or_insert(0) += 1;
+        }
+    }
+
+    pub fn hits(&self) -> &[u64] {
+        &self.hits
+    }
+
+    /// Taken jumps as `(from, to, count)`, including the ones leaving the program.
+    pub fn jumps(&self) -> impl Iterator<Item = (usize, usize, u64)> + '_ {
+        self.jumps
+            .iter()
+            .map(|(&(from, to), &count)| (from, to, count))
+    }
+
+    /// Back-edges, the most taken first.
+    pub fn back_edges(&self) -> Vec<BackEdge> {
+        let mut back_edges = self
+            .jumps()
+            .filter(|&(from, to, _)| to <= from)
+            .map(|(from, to, count)| BackEdge { from, to, count })
+            .collect::<Vec<_>>();
+
+        back_edges.sort_by_key(|edge| (u64::MAX - edge.count, edge.from));
+        back_edges
+    }
+
+    /// Executed basic blocks, the ones with the most executed instructions first.
+    ///
+    /// Blocks start at the first instruction, at every jump target and after every jump taken
+    /// during the run.
+    pub fn hot_blocks(&self) -> Vec<Block> {
+        let len = self.hits.len();
+
+        let mut leaders = BTreeSet::new();
+        leaders.insert(0);
+        for (from, to, _) in self.jumps() {
+            leaders.insert(to);
+            leaders.insert(from + 1);
+        }
+        leaders.insert(len);
+
+        let leaders = leaders
+            .into_iter()
+            .filter(|&ip| ip <= len)
+            .collect::<Vec<_>>();
+        let mut blocks = leaders
+            .windows(2)
+            .map(|bounds| Block {
+                start: bounds[0],
+                end: bounds[1],
+                entries: self.hits[bounds[0]],
+                executed: self.hits[bounds[0]..bounds[1]].iter().sum(),
+            })
+            .filter(|block| block.executed > 0)
+            .collect::<Vec<_>>();
+
+        blocks.sort_by_key(|block| (u64::MAX - block.executed, block.start));
+        blocks
+    }
+
+    /// Disassembly with the hits of every instruction and the jumps it took, followed by the
+    /// given number of hottest blocks and back-edges.
+    pub fn annotate(&self, instructions: &[Instruction], top: usize) -> String {
+        let mut report = format!("{:>12}  {:>4}  instruction\n", "hits", "ip");
+
+        for (ip, instruction) in instructions.iter().enumerate() {
+            let mut line = format!(
+                "{:>12}  {:>4}  {}",
+                self.hits.get(ip).copied().unwrap_or_default(),
+                ip,
+                disassemble_instruction(instruction)
+            );
+
+            for (_, to, count) in self.jumps().filter(|&(from, _, _)| from == ip) {
+                let kind = if to <= ip { " loop" } else { "" };
+                write!(line, "{:<1$}", "", JUMP_COLUMN.saturating_sub(line.len()))
+                    .and_then(|()| write!(line, "  -> {} ({}x{})", to, count, kind))
+                    .expect("Writing never fails.");
+            }
+
+            report += &line;
+            report += "\n";
+        }
+
+        report += "\nHottest blocks:\n";
+        for block in self.hot_blocks().iter().take(top) {
+            writeln!(
+                report,
+                "  {:>4}..{:<6}  {:>12} instructions in {} entries",
+                block.start, block.end, block.executed, block.entries
+            )
+            .expect("Writing never fails.");
+        }
+
+        report += "\nBack-edges:\n";
+        for edge in self.back_edges().iter().take(top) {
+            writeln!(
+                report,
+                "  {:>4} -> {:<4}  {:>12} times",
+                edge.from, edge.to, edge.count
+            )
+            .expect("Writing never fails.");
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{operation::Executor, program::Program, Result};
+
+    /// Adds 2 to register 1 until register 2 counted to 5.
+    const LOOP: &str = "#ip 3
+                        seti 0 0 1
+                        addi 1 2 1
+                        addi 2 1 2
+                        gtri 2 4 0
+                        addr 0 3 3
+                        seti 0 0 3";
+
+    fn profile() -> Result<(Program, Profile)> {
+        let program = LOOP.parse::<Program>()?;
+
+        let mut executor = Executor::<u32>::default()
+            .with_program(program.clone())
+            .with_profiling();
+        executor.run();
+
+        let profile = executor.profile().cloned().ok_or("Profiling is enabled.")?;
+        Ok((program, profile))
+    }
+
+    #[test]
+    fn enable_before_loading() -> Result<()> {
+        let (program, profile) = profile()?;
+
+        let mut executor = Executor::<u32>::default()
+            .with_profiling()
+            .with_program(program);
+        executor.run();
+
+        assert_eq!(Some(&profile), executor.profile());
+
+        Ok(())
+    }
+
+    #[test]
+    fn count_hits_and_jumps() -> Result<()> {
+        let (_, profile) = profile()?;
+
+        assert_eq!(&[1, 5, 5, 5, 5, 4], profile.hits());
+        assert_eq!(
+            vec![(4, 6, 1), (5, 1, 4)],
+            profile.jumps().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![BackEdge {
+                from: 5,
+                to: 1,
+                count: 4
+            }],
+            profile.back_edges()
+        );
+
+        let blocks = profile
+            .hot_blocks()
+            .iter()
+            .map(|block| (block.start, block.end, block.entries, block.executed))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(1, 5, 5, 20), (5, 6, 4, 4), (0, 1, 1, 1)], blocks);
+
+        Ok(())
+    }
+
+    #[test]
+    fn annotate_disassembly() -> Result<()> {
+        let (program, profile) = profile()?;
+        let report = profile.annotate(&program.instructions, 1);
+
+        assert!(report.contains("\n           5     3  gtri r2 4 r0\n"));
+        assert!(report.contains("\n           5     4  addr r0 r3 r3         -> 6 (1x)\n"));
+        assert!(report.contains("\n           4     5  seti 0 0 r3           -> 1 (4x loop)\n"));
+        assert!(report.ends_with(
+            "Hottest blocks:\n     1..5                 20 instructions in 5 entries\n\n\
+             Back-edges:\n     5 -> 1                4 times\n"
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use std::{collections::BTreeMap, error::Error, fmt, str::FromStr};
+
+use crate::{instruction::Instruction, Result};
+
+/// Instructions of a program, optionally preceded by an `#ip <register>` directive which binds
+/// the instruction pointer to a register.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+    pub ip_register: Option<u32>,
+    pub instructions: Vec<Instruction>,
+}
+
+impl FromStr for Program {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut program = Program::default();
+
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            match line.strip_prefix("#ip") {
+                Some(register) if program.ip_register.is_none() => {
+                    program.ip_register = Some(register.trim().parse()?)
+                }
+                Some(_) => Err(format!("Duplicate directive discovered: {}", line))?,
+                None => program.instructions.push(line.parse()?),
+            }
+        }
+
+        Ok(program)
+    }
+}
+
+/// Formats the program with numeric opcodes, see [`crate::assembly::disassemble`] for mnemonics.
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(register) = self.ip_register {
+            writeln!(f, "#ip {}", register)?;
+        }
+
+        for instruction in &self.instructions {
+            writeln!(f, "{}", instruction)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Translates the opcodes of the instructions into the opcodes of the executor.
+pub fn apply_opcode_mapping(
+    mut instructions: Vec<Instruction>,
+    mapping: &BTreeMap<u8, u8>,
+) -> Result<Vec<Instruction>> {
+    for instruction in instructions.iter_mut() {
+        instruction.opcode = *mapping
+            .get(&instruction.opcode)
+            .ok_or_else(|| format!("Unmapped opcode discovered: {:?}", instruction))?;
+    }
+
+    Ok(instructions)
+}
@@ -1,67 +1,248 @@
 use std::{
+    convert::TryFrom,
     error::Error,
-    ops::{Index, IndexMut},
+    fmt::{self, Debug, Display},
+    hash::Hash,
+    ops::{BitAnd, BitOr, Index, IndexMut},
     str::FromStr,
 };
 
-use lazy_static::lazy_static;
-use regex::Regex;
-
 use crate::Result;
 
-#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
-pub struct State(pub u32, pub u32, pub u32, pub u32);
+/// Type of the values stored inside the registers.
+pub trait Word:
+    Copy
+    + Debug
+    + Default
+    + Display
+    + FromStr
+    + Ord
+    + Hash
+    + Send
+    + Sync
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + 'static
+{
+    /// Converts the immediate value of an instruction.
+    fn from_u32(value: u32) -> Self;
+
+    fn from_bool(value: bool) -> Self;
+
+    /// Nothing if the value doesn't fit into the instruction pointer.
+    fn to_usize(self) -> Option<usize>;
+
+    /// Nothing if the instruction pointer doesn't fit into the word.
+    fn from_usize(value: usize) -> Option<Self>;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($word:ty),*) => {$(
+        impl Word for $word {
+            fn from_u32(value: u32) -> Self {
+                Self::from(value)
+            }
+
+            fn from_bool(value: bool) -> Self {
+                Self::from(value)
+            }
+
+            fn to_usize(self) -> Option<usize> {
+                usize::try_from(self).ok()
+            }
+
+            fn from_usize(value: usize) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$word>::checked_add(self, rhs)
+            }
+
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$word>::wrapping_add(self, rhs)
+            }
+
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$word>::saturating_add(self, rhs)
+            }
 
-impl Default for State {
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$word>::checked_mul(self, rhs)
+            }
+
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$word>::wrapping_mul(self, rhs)
+            }
+
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$word>::saturating_mul(self, rhs)
+            }
+        }
+    )*};
+}
+
+impl_word!(u32, u64, u128);
+
+/// Registers of the device, `N` of them holding a `WordT` each.
+///
+/// The puzzle of day 16 uses four registers, later device programs six.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct State<WordT = u32, const N: usize = 4>(pub [WordT; N]);
+
+/// Access to a register the state doesn't have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidRegister {
+    pub register: u32,
+    pub num_registers: usize,
+}
+
+impl<WordT: Word, const N: usize> State<WordT, N> {
+    pub fn get(&self, register: u32) -> std::result::Result<WordT, InvalidRegister> {
+        self.0
+            .get(register as usize)
+            .copied()
+            .ok_or(InvalidRegister {
+                register,
+                num_registers: N,
+            })
+    }
+
+    pub fn set(&mut self, register: u32, value: WordT) -> std::result::Result<(), InvalidRegister> {
+        let slot = self.0.get_mut(register as usize).ok_or(InvalidRegister {
+            register,
+            num_registers: N,
+        })?;
+
+        *slot = value;
+        Ok(())
+    }
+}
+
+impl<WordT: Word, const N: usize> Default for State<WordT, N> {
     fn default() -> Self {
-        Self(0, 0, 0, 0)
+        State([WordT::default(); N])
     }
 }
 
-impl Index<u8> for State {
-    type Output = u32;
+impl<WordT, const N: usize> Index<u32> for State<WordT, N> {
+    type Output = WordT;
 
-    fn index(&self, index: u8) -> &Self::Output {
-        match index {
-            0 => &self.0,
-            1 => &self.1,
-            2 => &self.2,
-            3 => &self.3,
-            _ => panic!("Invalid register index {}", index),
-        }
+    /// Panics for invalid registers, see [`State::get`] for the checked access.
+    fn index(&self, register: u32) -> &Self::Output {
+        &self.0[register as usize]
     }
 }
 
-impl IndexMut<u8> for State {
-    fn index_mut(&mut self, index: u8) -> &mut Self::Output {
-        match index {
-            0 => &mut self.0,
-            1 => &mut self.1,
-            2 => &mut self.2,
-            3 => &mut self.3,
-            _ => panic!("Invalid register index {}", index),
+impl<WordT, const N: usize> IndexMut<u32> for State<WordT, N> {
+    fn index_mut(&mut self, register: u32) -> &mut Self::Output {
+        &mut self.0[register as usize]
+    }
+}
+
+/// Formats the registers like the puzzle, e.g. `[3, 2, 1, 1]`.
+impl<WordT: Display, const N: usize> Display for State<WordT, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (index, value) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
         }
+        write!(f, "]")
     }
 }
 
-impl FromStr for State {
+impl<WordT: Word, const N: usize> FromStr for State<WordT, N>
+where
+    WordT::Err: Error + 'static,
+{
     type Err = Box<dyn Error>;
 
     fn from_str(input: &str) -> Result<Self> {
-        lazy_static! {
-            static ref STATE_RE: Regex = Regex::new(r"\[(\d+), (\d+), (\d+), (\d+)\]",)
-                .expect("Predefined state regex failed to compile.");
-        };
+        let values = input
+            .trim()
+            .strip_prefix('[')
+            .and_then(|input| input.strip_suffix(']'))
+            .ok_or_else(|| format!("Couldn't parse registers: {}.", input))?
+            .split(',')
+            .map(|value| value.trim().parse::<WordT>())
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        if values.len() != N {
+            Err(format!(
+                "Expected {} instead of {} registers: {}.",
+                N,
+                values.len(),
+                input
+            ))?;
+        }
+
+        let mut state = State::default();
+        state.0.copy_from_slice(&values);
+
+        Ok(state)
+    }
+}
 
-        let captures = STATE_RE
-            .captures(&input)
-            .ok_or_else(|| format!("Couldn't parse input entry line: {}.", input))?;
+impl Display for InvalidRegister {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid register {}, there are only {} registers.",
+            self.register, self.num_registers
+        )
+    }
+}
+
+impl Error for InvalidRegister {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_format() -> Result<()> {
+        let four = "[3, 2, 1, 1]".parse::<State>()?;
+        assert_eq!(State([3, 2, 1, 1]), four);
+        assert_eq!("[3, 2, 1, 1]", four.to_string());
+
+        let six = "[6, 5, 6, 0, 0, 9]".parse::<State<u64, 6>>()?;
+        assert_eq!(State([6, 5, 6, 0, 0, 9]), six);
+        assert_eq!("[6, 5, 6, 0, 0, 9]", six.to_string());
+
+        let wide = "[4294967296, 0, 0, 0]".parse::<State<u64>>()?;
+        assert_eq!(1 << 32, wide[0]);
 
-        Ok(State(
-            captures[1].parse()?,
-            captures[2].parse()?,
-            captures[3].parse()?,
-            captures[4].parse()?,
-        ))
+        assert!("[1, 2, 3]".parse::<State>().is_err());
+        assert!("[6, 5, 6, 0, 0, 9]".parse::<State>().is_err());
+        assert!("[4294967296, 0, 0, 0]".parse::<State>().is_err());
+        assert!("1, 2, 3, 4".parse::<State>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_register_access() {
+        let mut state = State::<u32, 6>::default();
+
+        assert_eq!(Ok(()), state.set(5, 7));
+        assert_eq!(Ok(7), state.get(5));
+
+        let invalid = InvalidRegister {
+            register: 6,
+            num_registers: 6,
+        };
+        assert_eq!(Err(invalid), state.get(6));
+        assert_eq!(Err(invalid), state.set(6, 1));
     }
 }
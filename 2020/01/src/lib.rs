@@ -0,0 +1,69 @@
+#![deny(clippy::pedantic)]
+
+use aoc_common::{Answer, Input, Solution};
+use itertools::Itertools;
+use std::convert::TryInto;
+
+pub use aoc_common::Error;
+
+#[must_use]
+pub fn find_entries_adding_to_2020<const SIZE: usize>(expenses: &[u32]) -> Option<[u32; SIZE]> {
+    let result = expenses
+        .iter()
+        .permutations(SIZE)
+        .find(|entries| entries.iter().copied().sum::<u32>() == 2020)?;
+
+    result.into_iter().copied().collect_vec().try_into().ok()
+}
+
+// ------------------------------------------------------------------------------
+// Puzzle
+// ------------------------------------------------------------------------------
+
+pub struct Puzzle {
+    expenses: Vec<u32>,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self, Error> {
+        let expenses = input.lines()?;
+        Ok(Self { expenses })
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        let [first, second] = find_entries_adding_to_2020::<2>(&self.expenses)
+            .ok_or("Couldn't find two entries adding to 2020.")?;
+
+        Ok((first * second).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        let [first, second, third] = find_entries_adding_to_2020::<3>(&self.expenses)
+            .ok_or("Couldn't find three entries adding to 2020.")?;
+
+        Ok((first * second * third).into())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn check_two_numbers_adding_to_2020() {
+        let numbers = vec![1721, 979, 366, 299, 675, 1456];
+        let [first, second] = find_entries_adding_to_2020::<2>(&numbers).unwrap();
+
+        assert_eq!(514579, first * second);
+    }
+
+    #[test]
+    fn check_three_numbers_adding_to_2020() {
+        let numbers = vec![1721, 979, 366, 299, 675, 1456];
+        let [first, second, third] = find_entries_adding_to_2020::<3>(&numbers).unwrap();
+
+        assert_eq!(241861950, first * second * third);
+    }
+}
@@ -0,0 +1,202 @@
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+
+use aoc_common::{Answer, Grid, Input, Solution};
+
+pub use aoc_common::Error;
+
+// ------------------------------------------------------------------------------
+// Map
+// ------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub struct Map(pub Vec<Row>);
+
+impl Map {
+    pub fn count_trees(&self, slope: &(usize, usize)) -> u128 {
+        let target_depth = self.0.len();
+
+        let mut num_trees = 0;
+        let mut position = (0, 0);
+
+        while position.1 < target_depth {
+            let row = &self.0[position.1].0;
+            let row_length = row.len();
+
+            if row[position.0] == Tile::Tree {
+                num_trees += 1;
+            }
+
+            position.0 = (position.0 + slope.0) % row_length;
+            position.1 += slope.1;
+        }
+
+        num_trees
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for row in &self.0 {
+            row.fmt(f)?;
+            f.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&Grid<char>> for Map {
+    type Error = Error;
+
+    fn try_from(grid: &Grid<char>) -> Result<Self, Self::Error> {
+        let rows = grid
+            .rows()
+            .map(|row| {
+                let tiles = row.iter().copied().map(Tile::try_from);
+                tiles.collect::<Result<_, _>>().map(Row)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Map(rows))
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Map::try_from(&Input::from(s.to_string()).grid()?)
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Row
+// ------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub struct Row(pub Vec<Tile>);
+
+impl Display for Row {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        self.0.iter().try_for_each(|tile| tile.fmt(f))
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Tile
+// ------------------------------------------------------------------------------
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum Tile {
+    Empty,
+    Tree,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Tile::Empty => f.write_char('.'),
+            Tile::Tree => f.write_char('#'),
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Tree),
+            _ => Err(format!("Unknown Tile: {}", value).into()),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Puzzle
+// ------------------------------------------------------------------------------
+
+pub struct Puzzle {
+    map: Map,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self, Error> {
+        let map = Map::try_from(&input.grid()?)?;
+        Ok(Self { map })
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(self.map.count_trees(&(3, 1)).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+        let num_trees = slopes
+            .iter()
+            .map(|slope| self.map.count_trees(slope))
+            .product::<u128>();
+
+        Ok(num_trees.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const MAP: &str = "..##.......\n\
+                       #...#...#..\n\
+                       .#....#..#.\n\
+                       ..#.#...#.#\n\
+                       .#...##..#.\n\
+                       ..#.##.....\n\
+                       .#.#.#....#\n\
+                       .#........#\n\
+                       #.##...#...\n\
+                       #...##....#\n\
+                       .#..#...#.#";
+
+    #[test]
+    fn count_trees_in_3_1_slope() -> Result<(), Error> {
+        let map = Map::from_str(MAP)?;
+        assert_eq!(7, map.count_trees(&(3, 1)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn count_trees_in_1_1_slope() -> Result<(), Error> {
+        let map = Map::from_str(MAP)?;
+        assert_eq!(2, map.count_trees(&(1, 1)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn count_trees_in_5_1_slope() -> Result<(), Error> {
+        let map = Map::from_str(MAP)?;
+        assert_eq!(3, map.count_trees(&(6, 1)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn count_trees_in_7_1_slope() -> Result<(), Error> {
+        let map = Map::from_str(MAP)?;
+        assert_eq!(4, map.count_trees(&(7, 1)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn count_trees_in_1_2_slope() -> Result<(), Error> {
+        let map = Map::from_str(MAP)?;
+        assert_eq!(2, map.count_trees(&(1, 2)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use aoc_common::{Answer, Input, Solution};
+use parse_display::{Display, FromStr};
+
+// ------------------------------------------------------------------------------
+// Plane
+// ------------------------------------------------------------------------------
+
+pub type RowCount = u32;
+pub type SeatCount = u32;
+
+const PLANE_SIZE: (RowCount, SeatCount) = (128, 8);
+
+/// Finds the only free seat that is surrounded by taken seats, expects sorted seat ids.
+pub fn find_free_seat_id(sorted_seat_ids: &[u32]) -> Option<u32> {
+    sorted_seat_ids
+        .windows(2)
+        .find(|ids| ids[0] + 2 == ids[1])
+        .map(|ids| ids[0] + 1)
+}
+
+// ------------------------------------------------------------------------------
+// BoardingPass
+// ------------------------------------------------------------------------------
+
+#[derive(Debug, Display, FromStr)]
+#[display("{row}{seat}")]
+pub struct BoardingPass {
+    #[from_str(regex = "[BF]+")]
+    row: String,
+    #[from_str(regex = "[LR]+")]
+    seat: String,
+}
+
+impl BoardingPass {
+    pub fn calc_seat_id(&self) -> u32 {
+        let (row, seat) = self.calc_seat_position();
+        row * 8 + seat
+    }
+
+    fn calc_seat_position(&self) -> (RowCount, SeatCount) {
+        (self.calc_row(), self.calc_seat())
+    }
+
+    fn calc_row(&self) -> RowCount {
+        let mut row = 0..PLANE_SIZE.0;
+        let mut step_size = PLANE_SIZE.0;
+
+        for hint in self.row.chars() {
+            step_size /= 2;
+
+            if 'B' == hint {
+                row.start += step_size;
+            } else {
+                row.end -= step_size;
+            }
+        }
+
+        row.start
+    }
+
+    fn calc_seat(&self) -> SeatCount {
+        let mut seat = 0..PLANE_SIZE.1;
+        let mut step_size = PLANE_SIZE.1;
+
+        for hint in self.seat.chars() {
+            step_size /= 2;
+
+            if 'R' == hint {
+                seat.start += step_size;
+            } else {
+                seat.end -= step_size;
+            }
+        }
+
+        seat.start
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Puzzle
+// ------------------------------------------------------------------------------
+
+pub struct Puzzle {
+    seat_ids: Vec<u32>,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self, Error> {
+        let mut seat_ids = input
+            .lines::<BoardingPass>()?
+            .iter()
+            .map(BoardingPass::calc_seat_id)
+            .collect::<Vec<_>>();
+
+        seat_ids.sort_unstable();
+        Ok(Self { seat_ids })
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        let max_seat_id = self
+            .seat_ids
+            .last()
+            .ok_or("There are no boarding passes.")?;
+        Ok((*max_seat_id).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        let seat_id = find_free_seat_id(&self.seat_ids).ok_or("Couldn't find a free seat.")?;
+        Ok(seat_id.into())
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Utility
+// ------------------------------------------------------------------------------
+
+pub use aoc_common::Error;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calc_score_example_boarding_passes() -> Result<(), Error> {
+        let examples: Vec<(&str, (RowCount, SeatCount), u32)> = vec![
+            ("BFFFBBFRRR", (70, 7), 567),
+            ("FFFBBBFRRR", (14, 7), 119),
+            ("BBFFBBFRLL", (102, 4), 820),
+        ];
+
+        for example in examples {
+            let pass = example.0.parse::<BoardingPass>()?;
+            assert_eq!(example.1 .0, pass.calc_row());
+            assert_eq!(example.1 .1, pass.calc_seat());
+            assert_eq!(example.2, pass.calc_seat_id());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_common::{Answer, Input, Solution};
+
+// ------------------------------------------------------------------------------
+// Groups
+// ------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+pub struct Group(Vec<Form>);
+
+impl Group {
+    pub fn generate_answer_set(&self) -> HashSet<char> {
+        self.0
+            .iter()
+            .flat_map(|form| form.0.iter())
+            .cloned()
+            .collect()
+    }
+
+    pub fn generate_consent_answer_set(&self) -> HashSet<char> {
+        let mut consent = HashSet::new();
+
+        for (idx, form) in self.0.iter().enumerate() {
+            if idx == 0 {
+                consent = consent.union(&form.0).cloned().collect();
+            } else {
+                consent = consent.intersection(&form.0).cloned().collect();
+            }
+        }
+
+        consent
+    }
+}
+
+impl FromStr for Group {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .map(FromStr::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Form
+// ------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+pub struct Form(HashSet<char>);
+
+impl FromStr for Form {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let answers = s.chars().filter(|c| c.is_alphabetic()).collect();
+        Ok(Self(answers))
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Puzzle
+// ------------------------------------------------------------------------------
+
+pub struct Puzzle {
+    groups: Vec<Group>,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self, Error> {
+        let groups = input.blocks()?;
+        Ok(Self { groups })
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        let num_yes = self
+            .groups
+            .iter()
+            .map(Group::generate_answer_set)
+            .map(|answers| answers.len())
+            .sum::<usize>();
+
+        Ok(num_yes.into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        let num_consent_yes = self
+            .groups
+            .iter()
+            .map(Group::generate_consent_answer_set)
+            .map(|answers| answers.len())
+            .sum::<usize>();
+
+        Ok(num_consent_yes.into())
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Utility
+// ------------------------------------------------------------------------------
+
+pub use aoc_common::Error;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWER_LIST: &str = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+
+    #[test]
+    fn calc_total_num_yes() -> Result<(), Error> {
+        let num_total_answers = ANSWER_LIST
+            .split("\n\n")
+            .filter_map(|entry| entry.parse::<Group>().ok())
+            .map(|group| group.generate_answer_set())
+            .map(|answers| answers.len())
+            .sum::<usize>();
+
+        assert_eq!(11, num_total_answers);
+        Ok(())
+    }
+
+    #[test]
+    fn calc_consent_num_yes() -> Result<(), Error> {
+        let num_total_answers = ANSWER_LIST
+            .split("\n\n")
+            .filter_map(|entry| entry.parse::<Group>().ok())
+            .map(|group| group.generate_consent_answer_set())
+            .map(|answers| answers.len())
+            .sum::<usize>();
+
+        assert_eq!(6, num_total_answers);
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use aoc_common::{Answer, Input, Solution};
+use itertools::Itertools;
+
+// ------------------------------------------------------------------------------
+// XMAS
+// ------------------------------------------------------------------------------
+
+pub struct XMAS {}
+
+impl XMAS {
+    pub fn find_weak_number(preamble_size: usize, numbers: impl AsRef<[u64]>) -> Option<u64> {
+        let numbers = numbers.as_ref();
+        if preamble_size >= numbers.len() {
+            return None;
+        }
+
+        for index in preamble_size..numbers.len() {
+            let number = numbers[index];
+
+            let preamble_start = index - preamble_size;
+            let is_sum_of_preamble = numbers
+                .iter()
+                .skip(preamble_start)
+                .take(preamble_size)
+                .permutations(2)
+                .map(|numbers| numbers.into_iter().sum::<u64>())
+                .any(|value| value == number);
+
+            if !is_sum_of_preamble {
+                return Some(number);
+            }
+        }
+
+        None
+    }
+
+    pub fn find_encryption_weakness(weak_number: u64, numbers: impl AsRef<[u64]>) -> Option<u64> {
+        let numbers = numbers.as_ref();
+
+        let (weak_index, _) = numbers
+            .iter()
+            .find_position(|&&number| number == weak_number)?;
+
+        for index in 0..weak_index - 2 {
+            for chunk_size in 2..weak_index {
+                let chunk = numbers
+                    .iter()
+                    .skip(index)
+                    .take(chunk_size)
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if weak_number == chunk.iter().sum::<u64>() {
+                    let min_value = chunk.iter().min()?;
+                    let max_value = chunk.iter().max()?;
+
+                    return Some(min_value + max_value);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Puzzle
+// ------------------------------------------------------------------------------
+
+pub struct Puzzle {
+    numbers: Vec<u64>,
+}
+
+impl Puzzle {
+    const PREAMBLE_SIZE: usize = 25;
+
+    fn find_weak_number(&self) -> Result<u64, Error> {
+        let weak_number = XMAS::find_weak_number(Self::PREAMBLE_SIZE, &self.numbers)
+            .ok_or("Couldn't determine weak number.")?;
+
+        Ok(weak_number)
+    }
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self, Error> {
+        let numbers = input.lines()?;
+        Ok(Self { numbers })
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(self.find_weak_number()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        let encryption_weakness =
+            XMAS::find_encryption_weakness(self.find_weak_number()?, &self.numbers)
+                .ok_or("Couldn't determine encryption weakness.")?;
+
+        Ok(encryption_weakness.into())
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Utility
+// ------------------------------------------------------------------------------
+
+pub use aoc_common::Error;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEQUENCE: &str = "35
+                            20
+                            15
+                            25
+                            47
+                            40
+                            62
+                            55
+                            65
+                            95
+                            102
+                            117
+                            150
+                            182
+                            127
+                            219
+                            299
+                            277
+                            309
+                            576";
+
+    #[test]
+    fn break_xmas_cipher() -> Result<(), Error> {
+        let number_sequence = SEQUENCE
+            .lines()
+            .map(str::trim)
+            .map(str::parse)
+            .collect::<Result<Vec<u64>, _>>()?;
+
+        let weak_entry = XMAS::find_weak_number(5, &number_sequence).unwrap();
+        assert_eq!(127, weak_entry);
+
+        let encryption_weakness =
+            XMAS::find_encryption_weakness(weak_entry, &number_sequence).unwrap();
+        assert_eq!(62, encryption_weakness);
+
+        Ok(())
+    }
+}
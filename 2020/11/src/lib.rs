@@ -0,0 +1,302 @@
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+
+use aoc_common::{Answer, Grid, Input, Solution};
+
+// ------------------------------------------------------------------------------
+// Map
+// ------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Map {
+    map: Vec<Row>,
+    size: (usize, usize),
+}
+
+impl Map {
+    pub fn advance_until_stall(&mut self) -> usize {
+        let mut num_occupied = self.num_occupied();
+
+        loop {
+            self.advance();
+
+            let updated_num_occupied = self.num_occupied();
+            if num_occupied == updated_num_occupied {
+                break;
+            }
+
+            num_occupied = updated_num_occupied;
+        }
+
+        num_occupied
+    }
+
+    fn advance(&mut self) {
+        let mut new_map = self.map.clone();
+
+        for (row, new_row) in new_map.iter_mut().enumerate() {
+            for (column, new_tile) in new_row.0.iter_mut().enumerate() {
+                *new_tile = self.update_tile(row, column);
+            }
+        }
+
+        self.map = new_map;
+    }
+
+    fn num_occupied(&self) -> usize {
+        self.map.iter().map(Row::num_occupied).sum()
+    }
+
+    fn get_tile(&self, row: usize, column: usize) -> Option<Tile> {
+        let row = self.map.get(row)?;
+        row.0.get(column).cloned()
+    }
+
+    fn trace_view(&self, position: (usize, usize), direction: (i32, i32)) -> Tile {
+        if direction == (0, 0) {
+            return self
+                .get_tile(position.0, position.1)
+                .expect("trace failed cause of wrong initial position");
+        }
+
+        let mut position = position;
+        loop {
+            let (update, out_of_map) = match direction.0 {
+                row if row > 0 => position.0.overflowing_add(1),
+                0 => position.0.overflowing_add(0),
+                _ => position.0.overflowing_sub(1),
+            };
+
+            if out_of_map {
+                return Tile::EmptySeat;
+            } else {
+                position.0 = update;
+            }
+
+            let (update, out_of_map) = match direction.1 {
+                column if column > 0 => position.1.overflowing_add(1),
+                0 => position.1.overflowing_add(0),
+                _ => position.1.overflowing_sub(1),
+            };
+
+            if out_of_map {
+                return Tile::EmptySeat;
+            } else {
+                position.1 = update;
+            }
+
+            match self.get_tile(position.0, position.1) {
+                Some(Tile::Floor) => {}
+                Some(seat) => return seat,
+                None => return Tile::EmptySeat,
+            }
+        }
+    }
+
+    fn update_tile(&self, row: usize, column: usize) -> Tile {
+        let tile = self.map[row].0[column];
+        if tile.is_floor() {
+            return tile;
+        }
+
+        let min_row = row.saturating_sub(1);
+        let max_row = row.saturating_add(1);
+
+        let min_column = column.saturating_sub(1);
+        let max_column = column.saturating_add(1);
+
+        let mut adjacent_tiles = vec![];
+        for adjacent_row in min_row..=max_row {
+            for adjacent_column in min_column..=max_column {
+                let mut row_direction =
+                    i32::try_from(adjacent_row).expect("num rows is way to large");
+                row_direction -= i32::try_from(row).expect("num rows is way to large");
+
+                let mut column_direction =
+                    i32::try_from(adjacent_column).expect("num columns is way to large");
+                column_direction -= i32::try_from(column).expect("num columns is way to large");
+
+                let direction = (row_direction, column_direction);
+                let tile = self.trace_view((row, column), direction);
+                adjacent_tiles.push(tile);
+            }
+        }
+
+        let num_occupied = adjacent_tiles
+            .into_iter()
+            .filter(|tile| tile.is_occupied_seat())
+            .count();
+
+        if tile.is_empty_seat() && num_occupied == 0 {
+            return Tile::OccupiedSeat;
+        }
+
+        if tile.is_occupied_seat() && num_occupied >= 6 {
+            return Tile::EmptySeat;
+        }
+
+        tile
+    }
+}
+
+impl TryFrom<&Grid<char>> for Map {
+    type Error = Error;
+
+    fn try_from(grid: &Grid<char>) -> Result<Self, Self::Error> {
+        let map = grid
+            .rows()
+            .map(|row| {
+                let tiles = row.iter().copied().map(Tile::try_from);
+                tiles.collect::<Result<_, _>>().map(Row)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Map {
+            map,
+            size: (grid.height(), grid.width()),
+        })
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for row in &self.map {
+            row.fmt(f)?;
+            f.write_str("\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Row
+// ------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Row(Vec<Tile>);
+
+impl Row {
+    fn num_occupied(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|&&tile| tile == Tile::OccupiedSeat)
+            .count()
+    }
+}
+
+impl Display for Row {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for tile in &self.0 {
+            tile.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Tile
+// ------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Tile {
+    Floor,
+    EmptySeat,
+    OccupiedSeat,
+}
+
+impl Tile {
+    fn is_floor(self) -> bool {
+        self == Tile::Floor
+    }
+
+    fn is_empty_seat(self) -> bool {
+        self == Tile::EmptySeat
+    }
+
+    fn is_occupied_seat(self) -> bool {
+        self == Tile::OccupiedSeat
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let tile = match value {
+            '.' => Self::Floor,
+            'L' => Self::EmptySeat,
+            '#' => Self::OccupiedSeat,
+            value => return Err(format!("Unknown Tile Value: {}", value).into()),
+        };
+
+        Ok(tile)
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let tile = match self {
+            Tile::Floor => '.',
+            Tile::EmptySeat => 'L',
+            Tile::OccupiedSeat => '#',
+        };
+
+        tile.fmt(f)
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Puzzle
+// ------------------------------------------------------------------------------
+
+pub struct Puzzle {
+    map: Map,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &Input) -> Result<Self, Error> {
+        let map = Map::try_from(&input.grid()?)?;
+        Ok(Self { map })
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Err("Part 1 of day 11 of 2020 isn't solved.".into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(self.map.clone().advance_until_stall().into())
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Utility
+// ------------------------------------------------------------------------------
+
+pub use aoc_common::Error;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "L.LL.LL.LL\n\
+                       LLLLLLL.LL\n\
+                       L.L.L..L..\n\
+                       LLLL.LL.LL\n\
+                       L.LL.LL.LL\n\
+                       L.LLLLL.LL\n\
+                       ..L.L.....\n\
+                       LLLLLLLLLL\n\
+                       L.LLLLLL.L\n\
+                       L.LLLLL.LL";
+
+    #[test]
+    fn simulate_5_rounds() -> Result<(), Error> {
+        let mut map = Map::try_from(&Input::from(MAP.to_string()).grid()?)?;
+        let num_occupied = map.advance_until_stall();
+
+        assert_eq!(26, num_occupied);
+        Ok(())
+    }
+}
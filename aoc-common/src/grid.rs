@@ -0,0 +1,74 @@
+use std::fmt;
+
+/// Rectangular grid of cells stored in reading order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<CellT> {
+    width: usize,
+    height: usize,
+    cells: Vec<CellT>,
+}
+
+impl<CellT> Grid<CellT> {
+    /// Creates a grid out of cells in reading order.
+    ///
+    /// Returns `None` if the number of cells doesn't match the dimensions.
+    pub fn new(width: usize, height: usize, cells: Vec<CellT>) -> Option<Self> {
+        if width * height != cells.len() {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&CellT> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.cells.get(y * self.width + x)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut CellT> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.cells.get_mut(y * self.width + x)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[CellT]> {
+        // `chunks` panics on a zero chunk size, an empty grid has no rows anyways.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates all cells in reading order together with their `(x, y)` coordinate.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &CellT)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| ((idx % width, idx / width), cell))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,308 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{Error, Grid};
+
+// ------------------------------------------------------------------------------
+// Input
+// ------------------------------------------------------------------------------
+
+/// Puzzle input read from a file, stdin or an embedded string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    content: String,
+}
+
+impl Input {
+    /// Reads the file passed as first command line argument.
+    ///
+    /// Falls back to stdin if no argument or `-` is passed.
+    pub fn from_args() -> Result<Self, InputError> {
+        match env::args_os().nth(1) {
+            Some(path) => Self::from_path(path),
+            None => Self::from_stdin(),
+        }
+    }
+
+    /// Reads the given file, `-` reads from stdin instead.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, InputError> {
+        let path = path.as_ref();
+
+        if path == Path::new("-") {
+            Self::from_stdin()
+        } else {
+            Self::from_file(path)
+        }
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, InputError> {
+        let path = path.as_ref();
+
+        fs::read_to_string(path)
+            .map(Self::from)
+            .map_err(|source| InputError::Read {
+                origin: Origin::File(path.to_path_buf()),
+                source,
+            })
+    }
+
+    pub fn from_stdin() -> Result<Self, InputError> {
+        let mut content = String::new();
+
+        io::stdin()
+            .read_to_string(&mut content)
+            .map(|_| Self::from(content))
+            .map_err(|source| InputError::Read {
+                origin: Origin::Stdin,
+                source,
+            })
+    }
+
+    /// Wraps an input embedded into the binary, e.g. via `include_str!`.
+    pub fn embedded(content: &str) -> Self {
+        Self::from(content.to_owned())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.content
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.content.as_bytes()
+    }
+
+    /// Parses the whole input, ignoring the final line break.
+    pub fn parse<ResultT>(&self) -> Result<ResultT, InputError>
+    where
+        ResultT: FromStr,
+        ResultT::Err: Into<Error>,
+    {
+        self.content
+            .trim_end_matches(&['\r', '\n'][..])
+            .parse()
+            .map_err(|err: ResultT::Err| InputError::Parse {
+                location: Location::Whole,
+                source: err.into(),
+            })
+    }
+
+    /// Parses every line of the input.
+    pub fn lines<ResultT>(&self) -> Result<Vec<ResultT>, InputError>
+    where
+        ResultT: FromStr,
+        ResultT::Err: Into<Error>,
+    {
+        parse_all(self.content.lines(), Location::Line)
+    }
+
+    /// Splits the input into blocks separated by a blank line.
+    pub fn split_blocks(&self) -> impl Iterator<Item = &str> {
+        self.content
+            .trim_end()
+            .split("\n\n")
+            .filter(|block| !block.is_empty())
+    }
+
+    /// Parses every block of lines separated by a blank line.
+    pub fn blocks<ResultT>(&self) -> Result<Vec<ResultT>, InputError>
+    where
+        ResultT: FromStr,
+        ResultT::Err: Into<Error>,
+    {
+        parse_all(self.split_blocks(), Location::Block)
+    }
+
+    /// Interprets the input as a rectangular grid of characters.
+    pub fn grid(&self) -> Result<Grid<char>, InputError> {
+        let mut width = None;
+        let mut cells = vec![];
+
+        for (idx, line) in self.content.lines().enumerate() {
+            let row_width = line.chars().count();
+            let expected = *width.get_or_insert(row_width);
+
+            if row_width != expected {
+                return Err(InputError::RaggedGrid {
+                    line: idx + 1,
+                    expected,
+                    found: row_width,
+                });
+            }
+
+            cells.extend(line.chars());
+        }
+
+        let width = width.unwrap_or(0);
+        let height = cells.len().checked_div(width).unwrap_or(0);
+
+        Ok(Grid::new(width, height, cells).expect("grid dimensions are validated while reading"))
+    }
+}
+
+impl From<String> for Input {
+    fn from(content: String) -> Self {
+        Self { content }
+    }
+}
+
+fn parse_all<'a, ResultT>(
+    parts: impl Iterator<Item = &'a str>,
+    location: fn(usize) -> Location,
+) -> Result<Vec<ResultT>, InputError>
+where
+    ResultT: FromStr,
+    ResultT::Err: Into<Error>,
+{
+    parts
+        .enumerate()
+        .map(|(idx, part)| {
+            part.parse().map_err(|err: ResultT::Err| InputError::Parse {
+                location: location(idx + 1),
+                source: err.into(),
+            })
+        })
+        .collect()
+}
+
+// ------------------------------------------------------------------------------
+// Errors
+// ------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub enum InputError {
+    Read {
+        origin: Origin,
+        source: io::Error,
+    },
+    Parse {
+        location: Location,
+        source: Error,
+    },
+    RaggedGrid {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// Where the content of an input was read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Origin {
+    File(PathBuf),
+    Stdin,
+}
+
+/// Part of an input which failed to be parsed, lines and blocks start at 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Location {
+    Whole,
+    Line(usize),
+    Block(usize),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Read {
+                origin: Origin::File(path),
+                source,
+            } => write!(f, "Couldn't read input file {}: {}", path.display(), source),
+            InputError::Read {
+                origin: Origin::Stdin,
+                source,
+            } => write!(f, "Couldn't read input from stdin: {}", source),
+            InputError::Parse {
+                location: Location::Whole,
+                source,
+            } => write!(f, "Couldn't parse input: {}", source),
+            InputError::Parse {
+                location: Location::Line(line),
+                source,
+            } => write!(f, "Couldn't parse line {} of the input: {}", line, source),
+            InputError::Parse {
+                location: Location::Block(block),
+                source,
+            } => write!(f, "Couldn't parse block {} of the input: {}", block, source),
+            InputError::RaggedGrid {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {} of the grid has {} instead of {} columns.",
+                line, found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Read { source, .. } => Some(source),
+            InputError::Parse { source, .. } => Some(source.as_ref()),
+            InputError::RaggedGrid { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_line() {
+        let input = Input::embedded("1\n2\n3\n");
+        assert_eq!(vec![1, 2, 3], input.lines::<u32>().unwrap());
+    }
+
+    #[test]
+    fn reports_invalid_line() {
+        let input = Input::embedded("1\nx\n3\n");
+
+        match input.lines::<u32>() {
+            Err(InputError::Parse { location, .. }) => assert_eq!(Location::Line(2), location),
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn splits_blocks() {
+        let input = Input::embedded("a\nb\n\nc\n\nd\ne\n");
+        let blocks = input.split_blocks().collect::<Vec<_>>();
+
+        assert_eq!(vec!["a\nb", "c", "d\ne"], blocks);
+    }
+
+    #[test]
+    fn parses_whole_input() {
+        let input = Input::embedded("286051\n");
+        assert_eq!(286_051, input.parse::<u32>().unwrap());
+    }
+
+    #[test]
+    fn reads_grid() {
+        let grid = Input::embedded("#.\n.#\n..\n").grid().unwrap();
+
+        assert_eq!((2, 3), (grid.width(), grid.height()));
+        assert_eq!(Some(&'#'), grid.get(1, 1));
+        assert_eq!(None, grid.get(2, 0));
+        assert_eq!("#.\n.#\n..\n", grid.to_string());
+    }
+
+    #[test]
+    fn rejects_ragged_grid() {
+        match Input::embedded("##\n#\n").grid() {
+            Err(InputError::RaggedGrid {
+                line,
+                expected,
+                found,
+            }) => assert_eq!((2, 2, 1), (line, expected, found)),
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! Functionality shared between the puzzles of every year.
+
+mod grid;
+mod input;
+mod solution;
+
+pub use grid::Grid;
+pub use input::{Input, InputError, Location, Origin};
+pub use solution::{run, Answer, Part, Solution};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<ContentT> = std::result::Result<ContentT, Error>;
@@ -0,0 +1,142 @@
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{Input, Result};
+
+// ------------------------------------------------------------------------------
+// Solution
+// ------------------------------------------------------------------------------
+
+/// Common interface of every puzzle.
+///
+/// The input is parsed once and both parts are solved based on the parsed
+/// representation, so parsing and solving can be measured independently.
+pub trait Solution: Sized {
+    fn parse(input: &Input) -> Result<Self>;
+
+    fn part1(&self) -> Result<Answer>;
+
+    fn part2(&self) -> Result<Answer>;
+
+    fn solve(&self, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
+
+/// Solves both parts of a puzzle based on the input passed via command line.
+///
+/// Meant to be used as `main` function of a single day.
+pub fn run<SolutionT: Solution>() -> Result<()> {
+    let input = Input::from_args()?;
+    let solution = SolutionT::parse(&input)?;
+
+    let mut num_failures = 0;
+    for &part in &Part::ALL {
+        match solution.solve(part) {
+            Ok(answer) => println!("Part {}: {}", part, answer),
+            Err(err) => {
+                eprintln!("Part {}: {}", part, err);
+                num_failures += 1;
+            }
+        }
+    }
+
+    if num_failures > 0 {
+        return Err(format!("{} part(s) couldn't be solved.", num_failures).into());
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------
+// Part
+// ------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        match input {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Puzzles only have part 1 and 2, not {}.", input)),
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(part: u8) -> std::result::Result<Self, Self::Error> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("Puzzles only have part 1 and 2, not {}.", part)),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Answer
+// ------------------------------------------------------------------------------
+
+/// Answer to a single part of a puzzle in the form it is submitted.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn new(answer: impl Display) -> Self {
+        Self(answer.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Answer {
+                fn from(answer: $type) -> Self {
+                    Self::new(answer)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String, &str
+);
@@ -0,0 +1,233 @@
+//! Benchmarks parsing and both parts of every registered puzzle.
+//!
+//! Every puzzle is its own benchmark group named `<year>-<day>`, containing the
+//! benchmarks `parse`, `part1` and `part2`. A single puzzle or part can be selected
+//! through the usual criterion filter, e.g. `cargo bench -p aoc -- 2018-12/part2`.
+//!
+//! After all benchmarks ran a summary table of the measured puzzles is printed,
+//! ordered by their total run time, and stored as `summary.md` next to the
+//! criterion reports.
+
+use std::{
+    convert::TryFrom,
+    env, fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use aoc::{manifest::Manifest, Visitor};
+use aoc_common::{Input, Part, Solution};
+use criterion::{black_box, Criterion};
+use serde::Deserialize;
+
+const BENCHMARKS: [&str; 3] = ["parse", "part1", "part2"];
+
+fn main() {
+    let started_at = SystemTime::now();
+
+    let manifest = Manifest::load().expect("answers manifest must be readable");
+
+    let mut benchmarks = Benchmarks {
+        criterion: Criterion::default().configure_from_args(),
+        manifest,
+        groups: vec![],
+    };
+
+    aoc::visit_all(&mut benchmarks);
+    benchmarks.criterion.final_summary();
+
+    print_summary(&benchmarks.groups, started_at);
+}
+
+// ------------------------------------------------------------------------------
+// Benchmarks
+// ------------------------------------------------------------------------------
+
+struct Benchmarks {
+    criterion: Criterion,
+    /// Only solved parts, which are listed inside the manifest, are measured.
+    manifest: Manifest,
+    groups: Vec<String>,
+}
+
+impl Visitor for Benchmarks {
+    fn visit<SolutionT: Solution>(&mut self, year: u16, day: u8) {
+        let group_name = format!("{}-{:02}", year, day);
+
+        let input = aoc::default_input(year, day)
+            .and_then(|path| Ok(Input::from_file(path)?))
+            .unwrap_or_else(|err| panic!("{}: {}", group_name, err));
+
+        let solution =
+            SolutionT::parse(&input).unwrap_or_else(|err| panic!("{}: {}", group_name, err));
+
+        let answers = self
+            .manifest
+            .answers
+            .iter()
+            .filter(|answer| (answer.year, answer.day) == (year, day))
+            .collect::<Vec<_>>();
+
+        let mut group = self.criterion.benchmark_group(&group_name);
+        if answers.iter().any(|answer| answer.slow) {
+            group.sample_size(10);
+        }
+
+        group.bench_function("parse", |bencher| {
+            bencher.iter(|| SolutionT::parse(black_box(&input)))
+        });
+
+        for answer in answers {
+            let part = Part::try_from(answer.part).unwrap_or_else(|err| panic!("{}", err));
+
+            group.bench_function(format!("part{}", part), |bencher| {
+                bencher.iter(|| black_box(&solution).solve(part))
+            });
+        }
+
+        group.finish();
+        self.groups.push(group_name);
+    }
+}
+
+// ------------------------------------------------------------------------------
+// Summary
+// ------------------------------------------------------------------------------
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+/// Mean run time and relative change to the previous run of a single benchmark.
+struct Measurement {
+    mean: Duration,
+    change: Option<f64>,
+}
+
+/// Reads the results of all benchmarks which ran since `started_at` from the criterion reports.
+fn print_summary(groups: &[String], started_at: SystemTime) {
+    let output_directory = criterion_directory();
+
+    let mut rows = groups
+        .iter()
+        .map(|group| {
+            let measurements = BENCHMARKS
+                .iter()
+                .map(|benchmark| {
+                    read_measurement(&output_directory.join(group).join(benchmark), started_at)
+                })
+                .collect::<Vec<_>>();
+
+            (group, measurements)
+        })
+        .filter(|(_, measurements)| measurements.iter().any(Option::is_some))
+        .collect::<Vec<_>>();
+
+    if rows.is_empty() {
+        return;
+    }
+
+    let total = |measurements: &[Option<Measurement>]| -> Duration {
+        measurements
+            .iter()
+            .flatten()
+            .map(|measurement| measurement.mean)
+            .sum()
+    };
+
+    rows.sort_by_key(|(_, measurements)| std::cmp::Reverse(total(measurements)));
+
+    let mut summary = String::from("| Puzzle | Parse | Part 1 | Part 2 | Total |\n");
+    summary += "|--------|------:|-------:|-------:|------:|\n";
+
+    for (group, measurements) in &rows {
+        let columns = measurements
+            .iter()
+            .map(|measurement| match measurement {
+                Some(measurement) => format_measurement(measurement),
+                None => String::from("-"),
+            })
+            .collect::<Vec<_>>();
+
+        summary += &format!(
+            "| {} | {} | {} |\n",
+            group,
+            columns.join(" | "),
+            format_duration(total(measurements))
+        );
+    }
+
+    println!("\n{}", summary);
+
+    let summary_file = output_directory.join("summary.md");
+    if let Err(err) = fs::write(&summary_file, &summary) {
+        eprintln!("Couldn't write {}: {}", summary_file.display(), err);
+    }
+}
+
+fn read_measurement(benchmark_directory: &Path, started_at: SystemTime) -> Option<Measurement> {
+    let estimates_file = benchmark_directory.join("new").join("estimates.json");
+
+    // Reports of benchmarks which were filtered out are left over from previous runs.
+    let modified_at = fs::metadata(&estimates_file)
+        .and_then(|meta| meta.modified())
+        .ok()?;
+    if modified_at < started_at {
+        return None;
+    }
+
+    let mean = read_estimates(&estimates_file)?.mean.point_estimate;
+    let change = read_estimates(&benchmark_directory.join("change").join("estimates.json"))
+        .map(|change| change.mean.point_estimate);
+
+    Some(Measurement {
+        mean: Duration::from_nanos(mean as u64),
+        change,
+    })
+}
+
+fn read_estimates(path: &Path) -> Option<Estimates> {
+    let content = fs::read(path).ok()?;
+    serde_json::from_slice(&content).ok()
+}
+
+/// Mirrors the lookup of the criterion output directory.
+fn criterion_directory() -> PathBuf {
+    if let Some(directory) = env::var_os("CRITERION_HOME") {
+        return PathBuf::from(directory);
+    }
+
+    env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| aoc::workspace_root().join("target"))
+        .join("criterion")
+}
+
+fn format_measurement(measurement: &Measurement) -> String {
+    let duration = format_duration(measurement.mean);
+
+    match measurement.change {
+        Some(change) => format!("{} ({:+.1}%)", duration, change * 100.0),
+        None => duration,
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    if nanos >= 1e9 {
+        format!("{:.2} s", nanos / 1e9)
+    } else if nanos >= 1e6 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else if nanos >= 1e3 {
+        format!("{:.2} µs", nanos / 1e3)
+    } else {
+        format!("{:.0} ns", nanos)
+    }
+}
@@ -0,0 +1,73 @@
+//! Registry of the solutions of every year and day.
+
+pub mod manifest;
+pub mod scaffold;
+mod year2018;
+mod year2020;
+
+use std::path::{Path, PathBuf};
+
+use aoc_common::{Answer, Input, Part, Result, Solution};
+
+/// Solves a single part of a puzzle based on the given input.
+pub type Solver = fn(&Input, Part) -> Result<Answer>;
+
+/// Receives the solution of every registered puzzle.
+pub trait Visitor {
+    fn visit<SolutionT: Solution>(&mut self, year: u16, day: u8);
+}
+
+/// Passes the solution of every registered puzzle to the visitor, ordered by year and day.
+pub fn visit_all(visitor: &mut impl Visitor) {
+    year2018::visit(visitor);
+    year2020::visit(visitor);
+}
+
+pub fn solve<SolutionT: Solution>(input: &Input, part: Part) -> Result<Answer> {
+    SolutionT::parse(input)?.solve(part)
+}
+
+/// Looks up the solution of the given puzzle.
+pub fn solver(year: u16, day: u8) -> Result<Solver> {
+    struct Lookup {
+        year: u16,
+        day: u8,
+        solver: Option<Solver>,
+    }
+
+    impl Visitor for Lookup {
+        fn visit<SolutionT: Solution>(&mut self, year: u16, day: u8) {
+            if (year, day) == (self.year, self.day) {
+                self.solver = Some(solve::<SolutionT>);
+            }
+        }
+    }
+
+    let mut lookup = Lookup {
+        year,
+        day,
+        solver: None,
+    };
+
+    visit_all(&mut lookup);
+    lookup
+        .solver
+        .ok_or_else(|| format!("There is no solution for day {} of {}.", day, year).into())
+}
+
+/// Locates the puzzle input stored inside the crate of the given day.
+pub fn default_input(year: u16, day: u8) -> Result<PathBuf> {
+    let relative_path = match year {
+        2018 => year2018::input_file(day),
+        2020 => year2020::input_file(day),
+        _ => return Err(format!("There are no solutions for {}.", year).into()),
+    };
+
+    Ok(workspace_root().join(relative_path))
+}
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The runner must be placed inside the workspace.")
+}
@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+use aoc_common::{Input, Part, Result};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Runs the Advent of Code solutions of every year and day.")]
+enum Command {
+    /// Solves a single puzzle and prints its answers.
+    Run {
+        #[structopt(long)]
+        year: u16,
+
+        #[structopt(long)]
+        day: u8,
+
+        /// Only solves the given part of the puzzle.
+        #[structopt(long, possible_values = &["1", "2"])]
+        part: Option<Part>,
+
+        /// Puzzle input file, `-` reads from stdin. Defaults to the input stored next to the day.
+        #[structopt(parse(from_os_str))]
+        input: Option<PathBuf>,
+    },
+    /// Creates the crate of a new day and registers it inside the workspace and the runner.
+    New { year: u16, day: u8 },
+}
+
+fn main() -> Result<()> {
+    match Command::from_args() {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::ALL.to_vec(),
+            };
+
+            let solver = aoc::solver(year, day)?;
+            let input = match input {
+                Some(path) => Input::from_path(path)?,
+                None => Input::from_file(aoc::default_input(year, day)?)?,
+            };
+
+            let mut num_failures = 0;
+            for part in parts {
+                match solver(&input, part) {
+                    // Multi-line answers, like rendered messages, start on their own line.
+                    Ok(answer) if answer.as_str().contains('\n') => {
+                        println!("{} day {:02} part {}:\n{}", year, day, part, answer)
+                    }
+                    Ok(answer) => println!("{} day {:02} part {}: {}", year, day, part, answer),
+                    Err(err) => {
+                        eprintln!("{} day {:02} part {}: {}", year, day, part, err);
+                        num_failures += 1;
+                    }
+                }
+            }
+
+            if num_failures > 0 {
+                return Err(format!("{} part(s) couldn't be solved.", num_failures).into());
+            }
+        }
+        Command::New { year, day } => {
+            let directory = aoc::scaffold::new_day(year, day)?;
+
+            println!("Created {}.", directory.display());
+            println!(
+                "Store the puzzle input in {} and record the answers in answers.toml once solved.",
+                directory.join("input").join("input.txt").display()
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::{convert::TryFrom, fs, path::PathBuf};
+
+use aoc_common::{Input, Part, Result};
+use serde::Deserialize;
+
+use crate::workspace_root;
+
+/// Checked-in list of the expected answers of every solved puzzle.
+#[derive(Debug, Deserialize)]
+pub struct Manifest {
+    #[serde(rename = "answer", default)]
+    pub answers: Vec<ExpectedAnswer>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExpectedAnswer {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Path of the puzzle input relative to the workspace root.
+    pub input: PathBuf,
+    pub expected: String,
+    /// Marks puzzles which take too long to be checked on every test run.
+    #[serde(default)]
+    pub slow: bool,
+}
+
+impl Manifest {
+    /// Loads the `answers.toml` stored in the workspace root.
+    pub fn load() -> Result<Self> {
+        let path = workspace_root().join("answers.toml");
+        let content = fs::read_to_string(&path)
+            .map_err(|err| format!("Couldn't read {}: {}", path.display(), err))?;
+
+        Ok(toml::from_str(&content)?)
+    }
+}
+
+impl ExpectedAnswer {
+    /// Solves the puzzle and compares the result with the expected answer.
+    pub fn check(&self) -> Result<()> {
+        let solver = crate::solver(self.year, self.day)?;
+        let input = Input::from_file(workspace_root().join(&self.input))?;
+
+        let answer = solver(&input, Part::try_from(self.part)?)?;
+        if answer.as_str() != self.expected {
+            return Err(format!("expected {}, got {}", self.expected, answer).into());
+        }
+
+        Ok(())
+    }
+}
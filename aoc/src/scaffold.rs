@@ -0,0 +1,379 @@
+//! Generates the crate of a new day and registers it inside the workspace and the runner.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::Result;
+
+use crate::workspace_root;
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/day/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/day/lib.rs.template");
+const MAIN_TEMPLATE: &str = include_str!("../templates/day/main.rs.template");
+const YEAR_TEMPLATE: &str = include_str!("../templates/year.rs.template");
+
+/// Name and workspace relative location of the crate of a single day.
+#[derive(Debug, PartialEq)]
+pub struct DayCrate {
+    pub name: String,
+    pub directory: PathBuf,
+    year: u16,
+    day: u8,
+}
+
+impl DayCrate {
+    /// Follows the naming scheme of the given year, 2018 used `aocNN` while later years use `aoc_YYYY_NN`.
+    pub fn new(year: u16, day: u8) -> Result<Self> {
+        if !(1..=25).contains(&day) {
+            return Err(format!("There is no day {} in an Advent of Code calendar.", day).into());
+        }
+
+        let (name, directory) = match year {
+            2018 => (format!("aoc{:02}", day), format!("{}/aoc{:02}", year, day)),
+            _ => (
+                format!("aoc_{}_{:02}", year, day),
+                format!("{}/{:02}", year, day),
+            ),
+        };
+
+        Ok(Self {
+            name,
+            directory: PathBuf::from(directory),
+            year,
+            day,
+        })
+    }
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{crate}", &self.name)
+            .replace("{year}", &self.year.to_string())
+            .replace("{day}", &format!("{:02}", self.day))
+            .replace("{day_number}", &self.day.to_string())
+    }
+}
+
+/// Creates the crate of the given day and registers it inside the workspace and the runner.
+///
+/// Returns the workspace relative location of the created crate.
+pub fn new_day(year: u16, day: u8) -> Result<PathBuf> {
+    create_day(workspace_root(), year, day)
+}
+
+/// Prepares every registration before anything gets written, so that a failing one leaves the
+/// workspace at `root` untouched and the day can be created once it is fixed.
+fn create_day(root: &Path, year: u16, day: u8) -> Result<PathBuf> {
+    let day_crate = DayCrate::new(year, day)?;
+
+    let crate_directory = root.join(&day_crate.directory);
+    if crate_directory.exists() {
+        return Err(format!("{} already exists.", day_crate.directory.display()).into());
+    }
+
+    let mut registrations = vec![
+        updated_file(&root.join("Cargo.toml"), |manifest| {
+            register_workspace_member(manifest, year)
+        })?,
+        updated_file(&root.join("aoc/Cargo.toml"), |manifest| {
+            register_dependency(manifest, &day_crate)
+        })?,
+    ];
+
+    let year_module = root.join(format!("aoc/src/year{}.rs", year));
+    if year_module.exists() {
+        registrations.push(updated_file(&year_module, |module| {
+            register_day(module, &day_crate)
+        })?);
+    } else {
+        registrations.push(updated_file(&root.join("aoc/src/lib.rs"), |lib| {
+            register_year(lib, year)
+        })?);
+        registrations.push((year_module, day_crate.render(YEAR_TEMPLATE)));
+    }
+
+    fs::create_dir_all(crate_directory.join("src"))?;
+    fs::create_dir_all(crate_directory.join("input"))?;
+    fs::write(
+        crate_directory.join("Cargo.toml"),
+        day_crate.render(CARGO_TEMPLATE),
+    )?;
+    fs::write(
+        crate_directory.join("src/lib.rs"),
+        day_crate.render(LIB_TEMPLATE),
+    )?;
+    fs::write(
+        crate_directory.join("src/main.rs"),
+        day_crate.render(MAIN_TEMPLATE),
+    )?;
+    fs::write(crate_directory.join("input/input.txt"), "")?;
+
+    for (path, content) in registrations {
+        fs::write(path, content)?;
+    }
+
+    Ok(day_crate.directory)
+}
+
+/// Path and updated content of the file, without writing it yet.
+fn updated_file(
+    path: &Path,
+    update: impl FnOnce(&str) -> Result<String>,
+) -> Result<(PathBuf, String)> {
+    let content = fs::read_to_string(path)?;
+    let updated = update(&content).map_err(|err| format!("{}: {}", path.display(), err))?;
+
+    Ok((path.to_path_buf(), updated))
+}
+
+// ------------------------------------------------------------------------------
+// Registration
+// ------------------------------------------------------------------------------
+
+/// Adds the `<year>/*` glob to the workspace members, days of known years are already covered.
+fn register_workspace_member(manifest: &str, year: u16) -> Result<String> {
+    let member = format!("\"{}/*\"", year);
+    if manifest.contains(&member) {
+        return Ok(manifest.to_string());
+    }
+
+    let last_year_member = manifest
+        .match_indices("/*\"")
+        .last()
+        .map(|(index, _)| index + "/*\"".len())
+        .ok_or("There are no year members inside the workspace.")?;
+
+    let mut updated = manifest.to_string();
+    updated.insert_str(last_year_member, &format!(", {}", member));
+    Ok(updated)
+}
+
+/// Adds the path dependency of the day crate to the runner, grouped by year.
+fn register_dependency(manifest: &str, day_crate: &DayCrate) -> Result<String> {
+    let key_of = |line: &str| {
+        let path = line.split("path = \"../").nth(1)?.split('"').next()?;
+        let (year, directory) = path.split_once('/')?;
+        let day = directory.trim_start_matches("aoc");
+
+        Some((year.parse::<u16>().ok()?, day.parse::<u8>().ok()?))
+    };
+
+    let mut dependency = format!(
+        "{} = {{ path = \"../{}\" }}",
+        day_crate.name,
+        day_crate.directory.display()
+    );
+
+    // The first day of a year starts a new group, separated by an empty line.
+    let years = manifest.lines().filter_map(key_of).map(|(year, _)| year);
+    if !years.clone().any(|year| year == day_crate.year) {
+        if years.clone().any(|year| year > day_crate.year) {
+            dependency.push('\n');
+        } else {
+            dependency.insert(0, '\n');
+        }
+    }
+
+    insert_sorted(
+        manifest,
+        &dependency,
+        (day_crate.year, day_crate.day),
+        key_of,
+    )
+}
+
+/// Adds the day to the visitor of an already registered year.
+fn register_day(module: &str, day_crate: &DayCrate) -> Result<String> {
+    let visit = day_crate.render("    visitor.visit::<{crate}::Puzzle>({year}, {day_number});");
+
+    insert_sorted(module, &visit, day_crate.day, |line| {
+        line.trim()
+            .strip_prefix("visitor.visit::<")?
+            .strip_suffix(");")?
+            .rsplit(", ")
+            .next()?
+            .parse()
+            .ok()
+    })
+}
+
+/// Adds the module of a new year to the registry.
+fn register_year(lib: &str, year: u16) -> Result<String> {
+    let year_of = |line: &str, prefix: &str| {
+        line.trim()
+            .strip_prefix(prefix)?
+            .get(..4)?
+            .parse::<u16>()
+            .ok()
+    };
+
+    let lib = insert_sorted(lib, &format!("mod year{};", year), year, |line| {
+        year_of(line, "mod year")
+    })?;
+    let lib = insert_sorted(
+        &lib,
+        &format!("    year{}::visit(visitor);", year),
+        year,
+        |line| year_of(line, "year").filter(|_| line.contains("::visit(")),
+    )?;
+
+    insert_sorted(
+        &lib,
+        &format!("        {0} => year{0}::input_file(day),", year),
+        year,
+        |line| year_of(line, "").filter(|_| line.contains("::input_file(")),
+    )
+}
+
+/// Inserts the line in front of the first line with a greater key, or behind the last keyed line.
+fn insert_sorted<KeyT: Copy + Ord>(
+    content: &str,
+    new_line: &str,
+    key: KeyT,
+    key_of: impl Fn(&str) -> Option<KeyT>,
+) -> Result<String> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let keyed_lines = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, key_of(line)?)))
+        .collect::<Vec<_>>();
+
+    if keyed_lines.iter().any(|&(_, existing)| existing == key) {
+        return Err(format!("`{}` is already registered.", new_line.trim()).into());
+    }
+
+    let index = match keyed_lines.iter().find(|&&(_, existing)| existing > key) {
+        Some(&(index, _)) => index,
+        None => keyed_lines
+            .last()
+            .map(|&(index, _)| index + 1)
+            .ok_or_else(|| format!("There is no place to register `{}`.", new_line.trim()))?,
+    };
+
+    let mut updated = lines[..index].to_vec();
+    updated.push(new_line);
+    updated.extend(&lines[index..]);
+
+    let mut updated = updated.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUNNER_MANIFEST: &str = r#"[dependencies]
+aoc-common = { path = "../aoc-common" }
+
+aoc01 = { path = "../2018/aoc01" }
+
+aoc_2020_01 = { path = "../2020/01" }
+aoc_2020_03 = { path = "../2020/03" }
+
+[dev-dependencies]
+"#;
+
+    #[test]
+    fn follow_naming_scheme_of_year() -> Result<()> {
+        let day_crate = DayCrate::new(2018, 7)?;
+        assert_eq!("aoc07", day_crate.name);
+        assert_eq!(Path::new("2018/aoc07"), day_crate.directory);
+
+        let day_crate = DayCrate::new(2021, 12)?;
+        assert_eq!("aoc_2021_12", day_crate.name);
+        assert_eq!(Path::new("2021/12"), day_crate.directory);
+
+        assert!(DayCrate::new(2021, 26).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn register_dependency_ordered_by_day() -> Result<()> {
+        let manifest = register_dependency(RUNNER_MANIFEST, &DayCrate::new(2020, 2)?)?;
+        assert!(manifest.contains(
+            "aoc_2020_01 = { path = \"../2020/01\" }\n\
+             aoc_2020_02 = { path = \"../2020/02\" }\n\
+             aoc_2020_03 = { path = \"../2020/03\" }\n"
+        ));
+
+        assert!(register_dependency(&manifest, &DayCrate::new(2020, 2)?).is_err());
+
+        let manifest = register_dependency(&manifest, &DayCrate::new(2021, 1)?)?;
+        assert!(manifest.contains(
+            "aoc_2020_03 = { path = \"../2020/03\" }\n\
+             \n\
+             aoc_2021_01 = { path = \"../2021/01\" }\n\
+             \n\
+             [dev-dependencies]"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn leave_workspace_untouched_on_failure() -> Result<()> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src"))?;
+
+        // Without any year member the workspace registration fails.
+        fs::write(root.join("Cargo.toml"), r#"members = ["aoc"]"#)?;
+        fs::write(root.join("aoc/Cargo.toml"), RUNNER_MANIFEST)?;
+        fs::write(root.join("aoc/src/year2020.rs"), "")?;
+
+        let created = create_day(&root, 2020, 2);
+        let crate_exists = root.join("2020/02").exists();
+        let manifest = fs::read_to_string(root.join("aoc/Cargo.toml"))?;
+        fs::remove_dir_all(&root)?;
+
+        assert!(created.is_err());
+        assert!(!crate_exists);
+        assert_eq!(RUNNER_MANIFEST, manifest);
+
+        Ok(())
+    }
+
+    #[test]
+    fn register_year_in_workspace_and_runner() -> Result<()> {
+        let workspace = r#"members = ["2018/*", "2020/*", "aoc", "aoc-common"]"#;
+        assert_eq!(
+            r#"members = ["2018/*", "2020/*", "2021/*", "aoc", "aoc-common"]"#,
+            register_workspace_member(workspace, 2021)?
+        );
+        assert_eq!(workspace, register_workspace_member(workspace, 2020)?);
+
+        let lib = "mod year2018;\n\
+                   \n\
+                   fn visit_all() {\n    \
+                       year2018::visit(visitor);\n\
+                   }\n\
+                   \n\
+                   fn default_input() {\n        \
+                       2018 => year2018::input_file(day),\n\
+                   }\n";
+
+        assert_eq!(
+            "mod year2018;\n\
+             mod year2021;\n\
+             \n\
+             fn visit_all() {\n    \
+                 year2018::visit(visitor);\n    \
+                 year2021::visit(visitor);\n\
+             }\n\
+             \n\
+             fn default_input() {\n        \
+                 2018 => year2018::input_file(day),\n        \
+                 2021 => year2021::input_file(day),\n\
+             }\n",
+            register_year(lib, 2021)?
+        );
+
+        Ok(())
+    }
+}
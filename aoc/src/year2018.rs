@@ -0,0 +1,25 @@
+use crate::Visitor;
+
+/// Path of the puzzle input relative to the workspace root.
+pub fn input_file(day: u8) -> String {
+    format!("2018/aoc{:02}/input/input.txt", day)
+}
+
+pub fn visit(visitor: &mut impl Visitor) {
+    visitor.visit::<aoc01::Puzzle>(2018, 1);
+    visitor.visit::<aoc02::Puzzle>(2018, 2);
+    visitor.visit::<aoc03::Puzzle>(2018, 3);
+    visitor.visit::<aoc04::Puzzle>(2018, 4);
+    visitor.visit::<aoc05::Puzzle>(2018, 5);
+    visitor.visit::<aoc06::Puzzle>(2018, 6);
+    visitor.visit::<aoc07::Puzzle>(2018, 7);
+    visitor.visit::<aoc08::Puzzle>(2018, 8);
+    visitor.visit::<aoc09::Puzzle>(2018, 9);
+    visitor.visit::<aoc10::Puzzle>(2018, 10);
+    visitor.visit::<aoc11::Puzzle>(2018, 11);
+    visitor.visit::<aoc12::Puzzle>(2018, 12);
+    visitor.visit::<aoc13::Puzzle>(2018, 13);
+    visitor.visit::<aoc14::Puzzle>(2018, 14);
+    visitor.visit::<aoc15::Puzzle>(2018, 15);
+    visitor.visit::<aoc16::Puzzle>(2018, 16);
+}
@@ -0,0 +1,20 @@
+use crate::Visitor;
+
+/// Path of the puzzle input relative to the workspace root.
+pub fn input_file(day: u8) -> String {
+    format!("2020/{:02}/input/input.txt", day)
+}
+
+pub fn visit(visitor: &mut impl Visitor) {
+    visitor.visit::<aoc_2020_01::Puzzle>(2020, 1);
+    visitor.visit::<aoc_2020_02::Puzzle>(2020, 2);
+    visitor.visit::<aoc_2020_03::Puzzle>(2020, 3);
+    visitor.visit::<aoc_2020_04::Puzzle>(2020, 4);
+    visitor.visit::<aoc_2020_05::Puzzle>(2020, 5);
+    visitor.visit::<aoc_2020_06::Puzzle>(2020, 6);
+    visitor.visit::<aoc_2020_07::Puzzle>(2020, 7);
+    visitor.visit::<aoc_2020_08::Puzzle>(2020, 8);
+    visitor.visit::<aoc_2020_09::Puzzle>(2020, 9);
+    visitor.visit::<aoc_2020_10::Puzzle>(2020, 10);
+    visitor.visit::<aoc_2020_11::Puzzle>(2020, 11);
+}
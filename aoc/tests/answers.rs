@@ -0,0 +1,33 @@
+use aoc::manifest::{ExpectedAnswer, Manifest};
+
+/// Checks all selected answers and reports every mismatch at once.
+fn check_answers(filter: impl Fn(&ExpectedAnswer) -> bool) {
+    let manifest = Manifest::load().expect("answers manifest must be readable");
+
+    let failures = manifest
+        .answers
+        .iter()
+        .filter(|answer| filter(answer))
+        .filter_map(|answer| {
+            answer.check().err().map(|err| {
+                format!(
+                    "{} day {:02} part {}: {}",
+                    answer.year, answer.day, answer.part, err
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn answers_match_manifest() {
+    check_answers(|answer| !answer.slow);
+}
+
+#[test]
+#[ignore = "takes several minutes in debug builds"]
+fn slow_answers_match_manifest() {
+    check_answers(|answer| answer.slow);
+}